@@ -0,0 +1,81 @@
+mod render;
+mod statistics;
+
+pub use render::{render_json, render_table};
+pub use statistics::Statistics;
+
+use crate::{
+    solution::{Part, Registration},
+    util::ParseError,
+};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    pub fn label(&self) -> String {
+        match self {
+            Phase::Parse => String::from("parse"),
+            Phase::Solve(part) => format!("part {}", part.number()),
+        }
+    }
+
+    pub fn key(&self) -> String {
+        match self {
+            Phase::Parse => String::from("parse"),
+            Phase::Solve(part) => format!("part_{}", part.number()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Benchmark {
+    pub day: u8,
+    pub title: &'static str,
+    pub iterations: usize,
+    pub phases: Vec<(Phase, Statistics)>,
+}
+
+pub fn benchmark(
+    registration: &Registration,
+    input: &str,
+    iterations: usize,
+) -> Result<Benchmark, ParseError> {
+    let parts = [Part::One, Part::Two];
+    let iterations = iterations.max(1);
+
+    let mut samples: Vec<(Phase, Vec<Duration>)> = vec![
+        (Phase::Parse, vec![]),
+        (Phase::Solve(Part::One), vec![]),
+        (Phase::Solve(Part::Two), vec![]),
+    ];
+
+    // warm up caches and allocator before measuring
+    registration.solution.run(input, &parts)?;
+
+    for _ in 0..iterations {
+        let report = registration.solution.run(input, &parts)?;
+
+        samples[0].1.push(report.parse);
+        for part in report.parts {
+            let index = part.part.number() as usize;
+            samples[index].1.push(part.duration);
+        }
+    }
+
+    let phases = samples
+        .into_iter()
+        .map(|(phase, durations)| (phase, Statistics::from_samples(&durations).unwrap()))
+        .collect();
+
+    Ok(Benchmark {
+        day: registration.day,
+        title: registration.title,
+        iterations,
+        phases,
+    })
+}
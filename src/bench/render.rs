@@ -0,0 +1,126 @@
+use super::Benchmark;
+use std::time::Duration;
+
+pub fn render_table(benchmarks: &[Benchmark]) -> String {
+    let mut lines = vec![format!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+        "day", "phase", "min", "median", "mean"
+    )];
+
+    for benchmark in benchmarks {
+        for (phase, statistics) in benchmark.phases.iter() {
+            lines.push(format!(
+                "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}",
+                benchmark.day,
+                phase.label(),
+                format!("{:.2?}", statistics.min),
+                format!("{:.2?}", statistics.median),
+                format!("{:.2?}", statistics.mean),
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
+
+pub fn render_json(benchmarks: &[Benchmark]) -> String {
+    let days: Vec<String> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            let phases: Vec<String> = benchmark
+                .phases
+                .iter()
+                .map(|(phase, statistics)| {
+                    format!(
+                        "\"{}\":{{\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{}}}",
+                        phase.key(),
+                        nanoseconds(statistics.min),
+                        nanoseconds(statistics.median),
+                        nanoseconds(statistics.mean),
+                    )
+                })
+                .collect();
+
+            format!(
+                "{{\"day\":{},\"title\":\"{}\",\"iterations\":{},\"phases\":{{{}}}}}",
+                benchmark.day,
+                escape(benchmark.title),
+                benchmark.iterations,
+                phases.join(",")
+            )
+        })
+        .collect();
+
+    format!("{{\"days\":[{}]}}", days.join(","))
+}
+
+fn nanoseconds(duration: Duration) -> u128 {
+    duration.as_nanos()
+}
+
+fn escape(input: &str) -> String {
+    input
+        .chars()
+        .flat_map(|character| match character {
+            '"' => vec!['\\', '"'],
+            '\\' => vec!['\\', '\\'],
+            _ => vec![character],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        bench::{Phase, Statistics},
+        solution::Part,
+    };
+
+    fn example() -> Vec<Benchmark> {
+        let statistics = |min, median, mean| Statistics {
+            min: Duration::from_micros(min),
+            median: Duration::from_micros(median),
+            mean: Duration::from_micros(mean),
+        };
+
+        vec![Benchmark {
+            day: 8,
+            title: "Play\"ground",
+            iterations: 3,
+            phases: vec![
+                (Phase::Parse, statistics(10, 12, 13)),
+                (Phase::Solve(Part::One), statistics(1, 2, 3)),
+            ],
+        }]
+    }
+
+    #[test]
+    fn renders_table() {
+        let result = render_table(&example());
+
+        assert_eq!(
+            result,
+            [
+                "day  phase            min        median          mean",
+                "  8  parse        10.00µs       12.00µs       13.00µs",
+                "  8  part 1        1.00µs        2.00µs        3.00µs",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn renders_json() {
+        let result = render_json(&example());
+
+        assert_eq!(
+            result,
+            concat!(
+                "{\"days\":[{\"day\":8,\"title\":\"Play\\\"ground\",\"iterations\":3,\"phases\":{",
+                "\"parse\":{\"min_ns\":10000,\"median_ns\":12000,\"mean_ns\":13000},",
+                "\"part_1\":{\"min_ns\":1000,\"median_ns\":2000,\"mean_ns\":3000}}}]}"
+            )
+        );
+    }
+}
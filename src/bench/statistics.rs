@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = match sorted.len().is_multiple_of(2) {
+            true => (sorted[middle - 1] + sorted[middle]) / 2,
+            false => sorted[middle],
+        };
+
+        let total: Duration = sorted.iter().sum();
+        let mean = total / sorted.len() as u32;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values
+            .iter()
+            .map(|value| Duration::from_millis(*value))
+            .collect()
+    }
+
+    #[test]
+    fn calculates_statistics() {
+        let result = Statistics::from_samples(&millis(&[9, 1, 5]));
+
+        assert_eq!(
+            result,
+            Some(Statistics {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(5),
+                mean: Duration::from_millis(5),
+            })
+        );
+
+        let result = Statistics::from_samples(&millis(&[4, 1, 2, 9]));
+
+        assert_eq!(
+            result,
+            Some(Statistics {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                mean: Duration::from_millis(4),
+            })
+        );
+    }
+
+    #[test]
+    fn has_no_statistics_without_samples() {
+        assert_eq!(Statistics::from_samples(&[]), None);
+    }
+}
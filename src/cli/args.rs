@@ -0,0 +1,671 @@
+use crate::{input::InputSource, solution::Part};
+use std::{fmt, path::PathBuf, str::FromStr};
+
+pub const USAGE: &str = "usage:
+  aoc run --day <N> [--part <1|2>] [--input <PATH|->] [--time] [--checked]
+  aoc run --all [--part <1|2>] [--jobs <N>] [--checked]
+  aoc bench [--day <N>] [--iterations <N>] [--json]
+  aoc verify [--day <N>] [--answers <PATH> | --examples] [--checked]
+  aoc new-day <N> [--title <TITLE>] [--model <NAME>]
+  aoc dial-trace [--part <1|2>] [--input <PATH|->] [--format <table|csv|json>]
+  aoc dial-starts [--input <PATH|->] [--zeroes <N>]
+  aoc list
+  aoc help
+
+options:
+  -d, --day <N>         day to solve
+  -a, --all             solve every day
+  -p, --part <1|2>      only solve the given part
+  -i, --input <PATH>    read the puzzle input from PATH, or from stdin when PATH is '-'
+  -t, --time            report parse and solve times
+  -j, --jobs <N>        number of days solved in parallel with --all (default: available cores)
+  -c, --checked         report arithmetic overflow in solvers instead of wrapping
+  -n, --iterations <N>  number of measured runs per day when benchmarking (default 10)
+      --json            print benchmark results as JSON
+      --answers <PATH>  verify against PATH instead of the answers.toml next to the inputs
+      --examples        verify the worked examples in src/day_NN/examples instead of the inputs
+      --title <TITLE>   puzzle title of the new day (default 'Day N')
+      --model <NAME>    module name of the new day's model (default 'model')
+  -f, --format <F>      dial trace format: table, csv or json (default table)
+  -z, --zeroes <N>      list the dial starts counting exactly N zeroes instead of the most
+
+dial-trace prints every rotation of the day 1 dial with its start, end and zeroes counted,
+using the part 2 counting rule unless --part 1 is given
+
+dial-starts prints the zeroes counted from every start position of the day 1 dial under both
+counting rules, followed by the starts counting the most zeroes
+
+without --input, inputs are read from $AOC_INPUT_DIR/day_NN.txt when AOC_INPUT_DIR is set,
+then from $XDG_CACHE_HOME/advent-of-code-2025/day_NN.txt, then from the inputs built into
+the binary";
+
+#[derive(Debug, PartialEq)]
+pub enum Days {
+    Single(u8),
+    All,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TraceFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        days: Days,
+        part: Option<Part>,
+        input: InputSource,
+        time: bool,
+        jobs: Option<usize>,
+        checked: bool,
+    },
+    Bench {
+        days: Days,
+        iterations: usize,
+        json: bool,
+    },
+    Verify {
+        days: Days,
+        answers: Option<PathBuf>,
+        examples: bool,
+        checked: bool,
+    },
+    NewDay {
+        day: u8,
+        title: Option<String>,
+        model: String,
+    },
+    DialTrace {
+        part: Part,
+        input: InputSource,
+        format: TraceFormat,
+    },
+    DialStarts {
+        input: InputSource,
+        zeroes: Option<u64>,
+    },
+    List,
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ArgsError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnknownArgument(String),
+    MissingValue(String),
+    InvalidValue { flag: String, value: String },
+    MissingDay,
+    Conflict(&'static str),
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::MissingCommand => write!(f, "no command given"),
+            ArgsError::UnknownCommand(command) => write!(f, "unknown command '{command}'"),
+            ArgsError::UnknownArgument(argument) => write!(f, "unknown argument '{argument}'"),
+            ArgsError::MissingValue(flag) => write!(f, "{flag} expects a value"),
+            ArgsError::InvalidValue { flag, value } => {
+                write!(f, "invalid value '{value}' for {flag}")
+            }
+            ArgsError::MissingDay => write!(f, "either --day or --all is required"),
+            ArgsError::Conflict(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, ArgsError> {
+    let mut args = args.iter();
+
+    let command = args.next().ok_or(ArgsError::MissingCommand)?;
+
+    match command.as_str() {
+        "run" => parse_run(args),
+        "bench" => parse_bench(args),
+        "verify" => parse_verify(args),
+        "new-day" => parse_new_day(args),
+        "dial-trace" => parse_dial_trace(args),
+        "dial-starts" => parse_dial_starts(args),
+        "list" => Ok(Command::List),
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        _ => Err(ArgsError::UnknownCommand(command.to_string())),
+    }
+}
+
+fn parse_run<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, ArgsError> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = InputSource::Default;
+    let mut time = false;
+    let mut jobs = None;
+    let mut checked = false;
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "-d" | "--day" => {
+                let value = expect_value(flag, args.next())?;
+                day = Some(parse_number(flag, value)?);
+            }
+            "-a" | "--all" => all = true,
+            "-p" | "--part" => {
+                let value = expect_value(flag, args.next())?;
+                part = Some(parse_part(flag, value)?);
+            }
+            "-i" | "--input" => {
+                let value = expect_value(flag, args.next())?;
+                input = match value.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(PathBuf::from(path)),
+                };
+            }
+            "-t" | "--time" => time = true,
+            "-j" | "--jobs" => {
+                let value = expect_value(flag, args.next())?;
+                jobs = Some(parse_number(flag, value)?);
+            }
+            "-c" | "--checked" => checked = true,
+            _ => return Err(ArgsError::UnknownArgument(flag.to_string())),
+        }
+    }
+
+    let days = match (day, all) {
+        (Some(_), true) => return Err(ArgsError::Conflict("--day and --all are exclusive")),
+        (Some(day), false) => Days::Single(day),
+        (None, true) => Days::All,
+        (None, false) => return Err(ArgsError::MissingDay),
+    };
+
+    if days == Days::All && input != InputSource::Default {
+        return Err(ArgsError::Conflict("--input can only be used with --day"));
+    }
+
+    if days != Days::All && jobs.is_some() {
+        return Err(ArgsError::Conflict("--jobs can only be used with --all"));
+    }
+
+    if jobs == Some(0) {
+        return Err(ArgsError::InvalidValue {
+            flag: String::from("--jobs"),
+            value: String::from("0"),
+        });
+    }
+
+    Ok(Command::Run {
+        days,
+        part,
+        input,
+        time,
+        jobs,
+        checked,
+    })
+}
+
+fn parse_bench<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, ArgsError> {
+    let mut days = Days::All;
+    let mut iterations = 10;
+    let mut json = false;
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "-d" | "--day" => {
+                let value = expect_value(flag, args.next())?;
+                days = Days::Single(parse_number(flag, value)?);
+            }
+            "-a" | "--all" => days = Days::All,
+            "-n" | "--iterations" => {
+                let value = expect_value(flag, args.next())?;
+                iterations = parse_number(flag, value)?;
+            }
+            "--json" => json = true,
+            _ => return Err(ArgsError::UnknownArgument(flag.to_string())),
+        }
+    }
+
+    if iterations == 0 {
+        return Err(ArgsError::InvalidValue {
+            flag: String::from("--iterations"),
+            value: iterations.to_string(),
+        });
+    }
+
+    Ok(Command::Bench {
+        days,
+        iterations,
+        json,
+    })
+}
+
+fn parse_verify<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, ArgsError> {
+    let mut days = Days::All;
+    let mut answers = None;
+    let mut examples = false;
+    let mut checked = false;
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "-d" | "--day" => {
+                let value = expect_value(flag, args.next())?;
+                days = Days::Single(parse_number(flag, value)?);
+            }
+            "-a" | "--all" => days = Days::All,
+            "--answers" => {
+                let value = expect_value(flag, args.next())?;
+                answers = Some(PathBuf::from(value));
+            }
+            "--examples" => examples = true,
+            "-c" | "--checked" => checked = true,
+            _ => return Err(ArgsError::UnknownArgument(flag.to_string())),
+        }
+    }
+
+    if examples && answers.is_some() {
+        return Err(ArgsError::Conflict(
+            "--answers and --examples are exclusive",
+        ));
+    }
+
+    Ok(Command::Verify {
+        days,
+        answers,
+        examples,
+        checked,
+    })
+}
+
+fn parse_new_day<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, ArgsError> {
+    let mut day: Option<u8> = None;
+    let mut title = None;
+    let mut model = String::from("model");
+
+    while let Some(argument) = args.next() {
+        match argument.as_str() {
+            "--title" => title = Some(expect_value(argument, args.next())?.to_string()),
+            "--model" => model = expect_value(argument, args.next())?.to_string(),
+            value if day.is_none() && !value.starts_with('-') => {
+                day = Some(parse_number("new-day", value)?);
+            }
+            _ => return Err(ArgsError::UnknownArgument(argument.to_string())),
+        }
+    }
+
+    let day = day.ok_or(ArgsError::MissingValue(String::from("new-day")))?;
+
+    if !(1..=25).contains(&day) {
+        return Err(ArgsError::InvalidValue {
+            flag: String::from("new-day"),
+            value: day.to_string(),
+        });
+    }
+
+    if !is_module_name(&model) {
+        return Err(ArgsError::InvalidValue {
+            flag: String::from("--model"),
+            value: model,
+        });
+    }
+
+    Ok(Command::NewDay { day, title, model })
+}
+
+fn parse_dial_trace<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, ArgsError> {
+    let mut part = Part::Two;
+    let mut input = InputSource::Default;
+    let mut format = TraceFormat::Table;
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "-p" | "--part" => {
+                let value = expect_value(flag, args.next())?;
+                part = parse_part(flag, value)?;
+            }
+            "-i" | "--input" => {
+                let value = expect_value(flag, args.next())?;
+                input = match value.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(PathBuf::from(path)),
+                };
+            }
+            "-f" | "--format" => {
+                let value = expect_value(flag, args.next())?;
+                format = match value.as_str() {
+                    "table" => TraceFormat::Table,
+                    "csv" => TraceFormat::Csv,
+                    "json" => TraceFormat::Json,
+                    _ => {
+                        return Err(ArgsError::InvalidValue {
+                            flag: flag.to_string(),
+                            value: value.to_string(),
+                        });
+                    }
+                };
+            }
+            _ => return Err(ArgsError::UnknownArgument(flag.to_string())),
+        }
+    }
+
+    Ok(Command::DialTrace {
+        part,
+        input,
+        format,
+    })
+}
+
+fn parse_dial_starts<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, ArgsError> {
+    let mut input = InputSource::Default;
+    let mut zeroes = None;
+
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "-i" | "--input" => {
+                let value = expect_value(flag, args.next())?;
+                input = match value.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(PathBuf::from(path)),
+                };
+            }
+            "-z" | "--zeroes" => {
+                let value = expect_value(flag, args.next())?;
+                zeroes = Some(parse_number(flag, value)?);
+            }
+            _ => return Err(ArgsError::UnknownArgument(flag.to_string())),
+        }
+    }
+
+    Ok(Command::DialStarts { input, zeroes })
+}
+
+fn is_module_name(name: &str) -> bool {
+    let mut characters = name.chars();
+
+    let starts_with_letter = characters
+        .next()
+        .is_some_and(|first| first.is_ascii_lowercase());
+
+    starts_with_letter
+        && characters.all(|character| {
+            character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_'
+        })
+        && name != "mod"
+}
+
+fn expect_value<'a>(flag: &str, value: Option<&'a String>) -> Result<&'a String, ArgsError> {
+    value.ok_or_else(|| ArgsError::MissingValue(flag.to_string()))
+}
+
+fn parse_number<T: FromStr>(flag: &str, value: &str) -> Result<T, ArgsError> {
+    value.parse().map_err(|_| ArgsError::InvalidValue {
+        flag: flag.to_string(),
+        value: value.to_string(),
+    })
+}
+
+fn parse_part(flag: &str, value: &str) -> Result<Part, ArgsError> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(ArgsError::InvalidValue {
+            flag: flag.to_string(),
+            value: value.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_args(input: &str) -> Vec<String> {
+        input.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_single_day() {
+        let result = parse_args(&to_args("run --day 7 --part 2 --input some/path.txt"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Run {
+                days: Days::Single(7),
+                part: Some(Part::Two),
+                input: InputSource::File(PathBuf::from("some/path.txt")),
+                time: false,
+                jobs: None,
+                checked: false,
+            })
+        );
+
+        let result = parse_args(&to_args("run -d 3 -i - --time"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Run {
+                days: Days::Single(3),
+                part: None,
+                input: InputSource::Stdin,
+                time: true,
+                jobs: None,
+                checked: false,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_all_days() {
+        let result = parse_args(&to_args("run --all"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Run {
+                days: Days::All,
+                part: None,
+                input: InputSource::Default,
+                time: false,
+                jobs: None,
+                checked: false,
+            })
+        );
+
+        let result = parse_args(&to_args("run --all --part 1 --jobs 4 --checked"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Run {
+                days: Days::All,
+                part: Some(Part::One),
+                input: InputSource::Default,
+                time: false,
+                jobs: Some(4),
+                checked: true,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_bench() {
+        let result = parse_args(&to_args("bench"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Bench {
+                days: Days::All,
+                iterations: 10,
+                json: false,
+            })
+        );
+
+        let result = parse_args(&to_args("bench --day 8 -n 3 --json"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Bench {
+                days: Days::Single(8),
+                iterations: 3,
+                json: true,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_verify() {
+        let result = parse_args(&to_args("verify --day 5 --answers answers.toml"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Verify {
+                days: Days::Single(5),
+                answers: Some(PathBuf::from("answers.toml")),
+                examples: false,
+                checked: false,
+            })
+        );
+
+        let result = parse_args(&to_args("verify --examples -c"));
+
+        assert_eq!(
+            result,
+            Ok(Command::Verify {
+                days: Days::All,
+                answers: None,
+                examples: true,
+                checked: true,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_new_day() {
+        assert_eq!(
+            parse_args(&to_args("new-day 10")),
+            Ok(Command::NewDay {
+                day: 10,
+                title: None,
+                model: String::from("model"),
+            })
+        );
+
+        assert_eq!(
+            parse_args(&to_args("new-day --model tile_map 11 --title Reactor")),
+            Ok(Command::NewDay {
+                day: 11,
+                title: Some(String::from("Reactor")),
+                model: String::from("tile_map"),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_dial_trace() {
+        assert_eq!(
+            parse_args(&to_args("dial-trace")),
+            Ok(Command::DialTrace {
+                part: Part::Two,
+                input: InputSource::Default,
+                format: TraceFormat::Table,
+            })
+        );
+
+        assert_eq!(
+            parse_args(&to_args("dial-trace -p 1 --input - --format csv")),
+            Ok(Command::DialTrace {
+                part: Part::One,
+                input: InputSource::Stdin,
+                format: TraceFormat::Csv,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_dial_starts() {
+        assert_eq!(
+            parse_args(&to_args("dial-starts")),
+            Ok(Command::DialStarts {
+                input: InputSource::Default,
+                zeroes: None,
+            })
+        );
+
+        assert_eq!(
+            parse_args(&to_args("dial-starts --input - -z 3")),
+            Ok(Command::DialStarts {
+                input: InputSource::Stdin,
+                zeroes: Some(3),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert_eq!(parse_args(&[]), Err(ArgsError::MissingCommand));
+        assert_eq!(parse_args(&to_args("run")), Err(ArgsError::MissingDay));
+        assert_eq!(
+            parse_args(&to_args("run --day")),
+            Err(ArgsError::MissingValue("--day".to_string()))
+        );
+        assert_eq!(
+            parse_args(&to_args("run --day 1 --part 3")),
+            Err(ArgsError::InvalidValue {
+                flag: "--part".to_string(),
+                value: "3".to_string()
+            })
+        );
+        assert_eq!(
+            parse_args(&to_args("run --all --input -")),
+            Err(ArgsError::Conflict("--input can only be used with --day"))
+        );
+        assert_eq!(
+            parse_args(&to_args("run --day 3 --jobs 2")),
+            Err(ArgsError::Conflict("--jobs can only be used with --all"))
+        );
+        assert_eq!(
+            parse_args(&to_args("run --all --jobs 0")),
+            Err(ArgsError::InvalidValue {
+                flag: "--jobs".to_string(),
+                value: "0".to_string()
+            })
+        );
+        assert_eq!(
+            parse_args(&to_args("bench --iterations 0")),
+            Err(ArgsError::InvalidValue {
+                flag: "--iterations".to_string(),
+                value: "0".to_string()
+            })
+        );
+        assert_eq!(
+            parse_args(&to_args("new-day 26")),
+            Err(ArgsError::InvalidValue {
+                flag: "new-day".to_string(),
+                value: "26".to_string()
+            })
+        );
+        assert_eq!(
+            parse_args(&to_args("new-day 10 --model Grid")),
+            Err(ArgsError::InvalidValue {
+                flag: "--model".to_string(),
+                value: "Grid".to_string()
+            })
+        );
+        assert_eq!(
+            parse_args(&to_args("dial-trace --format xml")),
+            Err(ArgsError::InvalidValue {
+                flag: "--format".to_string(),
+                value: "xml".to_string()
+            })
+        );
+        assert_eq!(
+            parse_args(&to_args("dial-starts --zeroes many")),
+            Err(ArgsError::InvalidValue {
+                flag: "--zeroes".to_string(),
+                value: "many".to_string()
+            })
+        );
+        assert_eq!(
+            parse_args(&to_args("solve 1")),
+            Err(ArgsError::UnknownCommand("solve".to_string()))
+        );
+    }
+}
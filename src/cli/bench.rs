@@ -0,0 +1,30 @@
+use super::{args::Days, select};
+use crate::{
+    bench::{benchmark, render_json, render_table},
+    input::{InputResolver, InputSource},
+};
+
+pub fn bench(days: &Days, iterations: usize, json: bool) -> Result<(), String> {
+    let registrations = select(days)?;
+    let resolver = InputResolver::from_env();
+
+    let mut benchmarks = vec![];
+
+    for registration in registrations {
+        let input = resolver
+            .resolve(registration.day, &InputSource::Default, registration.input)
+            .map_err(|error| error.to_string())?;
+
+        let result = benchmark(registration, &input, iterations)
+            .map_err(|error| format!("day {}, {error}", registration.day))?;
+
+        benchmarks.push(result);
+    }
+
+    match json {
+        true => println!("{}", render_json(&benchmarks)),
+        false => println!("{}", render_table(&benchmarks)),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::{
+    day_01,
+    input::{InputResolver, InputSource},
+    solution::Part,
+};
+
+pub fn dial_starts(source: &InputSource, zeroes: Option<u64>) -> Result<(), String> {
+    let input = InputResolver::from_env()
+        .resolve(1, source, day_01::INPUT)
+        .map_err(|error| error.to_string())?;
+
+    let landings =
+        day_01::analyse_starts(&input, Part::One).map_err(|error| format!("day 1, {error}"))?;
+    let passes =
+        day_01::analyse_starts(&input, Part::Two).map_err(|error| format!("day 1, {error}"))?;
+
+    println!("{:>5}  {:>7}  {:>12}", "start", "land-on", "pass-through");
+
+    for (start, (landed, passed)) in landings.zeroes().iter().zip(passes.zeroes()).enumerate() {
+        println!("{start:>5}  {landed:>7}  {passed:>12}");
+    }
+
+    println!();
+
+    for (name, analysis) in [("land-on", landings), ("pass-through", passes)] {
+        let (count, starts) = match zeroes {
+            Some(count) => (count, analysis.starts_with(count)),
+            None => analysis.most_zeroes(),
+        };
+
+        let starts: Vec<String> = starts.iter().map(u64::to_string).collect();
+
+        match starts.is_empty() {
+            true => println!("{name}: no start gives {count} zeroes"),
+            false => println!("{name}: {count} zeroes from starts {}", starts.join(", ")),
+        }
+    }
+
+    Ok(())
+}
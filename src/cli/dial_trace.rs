@@ -0,0 +1,24 @@
+use super::args::TraceFormat;
+use crate::{
+    day_01::{self, render_csv, render_json, render_table},
+    input::{InputResolver, InputSource},
+    solution::Part,
+};
+
+pub fn dial_trace(part: Part, source: &InputSource, format: TraceFormat) -> Result<(), String> {
+    let input = InputResolver::from_env()
+        .resolve(1, source, day_01::INPUT)
+        .map_err(|error| error.to_string())?;
+
+    let steps = day_01::trace(&input, part).map_err(|error| format!("day 1, {error}"))?;
+
+    let output = match format {
+        TraceFormat::Table => render_table(&steps),
+        TraceFormat::Csv => render_csv(&steps),
+        TraceFormat::Json => render_json(&steps),
+    };
+
+    println!("{output}");
+
+    Ok(())
+}
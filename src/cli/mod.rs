@@ -0,0 +1,83 @@
+mod args;
+mod bench;
+mod dial_starts;
+mod dial_trace;
+mod new_day;
+mod run;
+mod verify;
+
+pub use args::{Command, USAGE, parse_args};
+
+use crate::{
+    solution::{REGISTRY, Registration, find},
+    util::set_checked,
+};
+use args::Days;
+
+pub fn execute(command: Command) -> Result<(), String> {
+    match command {
+        Command::Run {
+            days,
+            part,
+            input,
+            time,
+            jobs,
+            checked,
+        } => {
+            if checked {
+                set_checked(true);
+            }
+            run::run(&days, part, &input, time, jobs)
+        }
+        Command::Bench {
+            days,
+            iterations,
+            json,
+        } => bench::bench(&days, iterations, json),
+        Command::Verify {
+            days,
+            answers,
+            examples,
+            checked,
+        } => {
+            if checked {
+                set_checked(true);
+            }
+            match examples {
+                true => verify::verify_examples(&days),
+                false => verify::verify(&days, answers.as_deref()),
+            }
+        }
+        Command::NewDay { day, title, model } => new_day::new_day(day, title, model),
+        Command::DialTrace {
+            part,
+            input,
+            format,
+        } => dial_trace::dial_trace(part, &input, format),
+        Command::DialStarts { input, zeroes } => dial_starts::dial_starts(&input, zeroes),
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Help => {
+            println!("{USAGE}");
+            Ok(())
+        }
+    }
+}
+
+fn list() {
+    for Registration { day, title, .. } in REGISTRY {
+        println!("day {day:>2}: {title}");
+    }
+}
+
+fn select(days: &Days) -> Result<Vec<&'static Registration>, String> {
+    match days {
+        Days::Single(day) => match find(*day) {
+            Some(registration) => Ok(vec![registration]),
+            None => Err(format!("day {day} is not yet solved")),
+        },
+        Days::All => Ok(REGISTRY.iter().collect()),
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    scaffold::{NewDay, format, scaffold},
+    solution::find,
+};
+use std::env;
+
+pub fn new_day(day: u8, title: Option<String>, model: String) -> Result<(), String> {
+    if find(day).is_some() {
+        return Err(format!("day {day} is already registered"));
+    }
+
+    let root = env::current_dir().map_err(|error| error.to_string())?;
+
+    let new_day = NewDay {
+        day,
+        title: title.unwrap_or_else(|| format!("Day {day}")),
+        model,
+    };
+
+    for path in scaffold(&root, &new_day).map_err(|error| error.to_string())? {
+        println!(
+            "wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+
+    if !format(&root) {
+        println!("could not run rustfmt, run `cargo fmt` to format the registration");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use super::{args::Days, select};
+use crate::{
+    input::{InputResolver, InputSource},
+    runner::{render_summary, run_days},
+    solution::{Part, PartReport},
+};
+use std::thread;
+
+pub fn run(
+    days: &Days,
+    part: Option<Part>,
+    source: &InputSource,
+    time: bool,
+    jobs: Option<usize>,
+) -> Result<(), String> {
+    let registrations = select(days)?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let resolver = InputResolver::from_env();
+
+    if *days == Days::All {
+        let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from));
+
+        let runs = run_days(
+            &registrations,
+            &parts,
+            |registration| {
+                resolver
+                    .resolve(registration.day, source, registration.input)
+                    .map_err(|error| error.to_string())
+            },
+            jobs,
+        );
+
+        println!("{}", render_summary(&runs, &parts));
+
+        let failed = runs.iter().filter(|run| !run.is_success()).count();
+
+        return match failed {
+            0 => Ok(()),
+            _ => Err(format!("{failed} of {} days failed", runs.len())),
+        };
+    }
+
+    let mut failed = false;
+
+    for registration in registrations {
+        let day = registration.day;
+        let input = resolver
+            .resolve(day, source, registration.input)
+            .map_err(|error| error.to_string())?;
+
+        let report = registration
+            .solution
+            .run(&input, &parts)
+            .map_err(|error| format!("day {day}, {error}"))?;
+
+        println!("day {day}");
+        if time {
+            println!("parse: {:.2?}", report.parse);
+        }
+
+        for PartReport {
+            part,
+            answer,
+            duration,
+        } in report.parts
+        {
+            let answer = match answer {
+                Ok(answer) => answer.to_string(),
+                Err(message) => {
+                    failed = true;
+                    format!("panicked: {message}")
+                }
+            };
+
+            match time {
+                true => println!("part {}: {answer} ({duration:.2?})", part.number()),
+                false => println!("part {}: {answer}", part.number()),
+            }
+        }
+    }
+
+    match failed {
+        true => Err(String::from("some parts panicked")),
+        false => Ok(()),
+    }
+}
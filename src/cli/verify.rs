@@ -0,0 +1,119 @@
+use super::{args::Days, select};
+use crate::{
+    input::{InputResolver, InputSource},
+    solution::Part,
+    verify::{
+        ANSWERS, AnswerSheet, Outcome, Verification, verify as verify_report, verify_example,
+    },
+};
+use std::path::Path;
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+impl Tally {
+    fn record(&mut self, verification: Verification, source: &str) {
+        let label = format!(
+            "day {} part {}{source}",
+            verification.day,
+            verification.part.number()
+        );
+
+        match verification.outcome {
+            Outcome::Pass => {
+                self.passed += 1;
+                println!("{label}: pass");
+            }
+            Outcome::Fail { expected } => {
+                self.failed += 1;
+                println!(
+                    "{label}: FAIL (expected {expected}, got {})",
+                    verification.actual
+                );
+            }
+            Outcome::Panicked => {
+                self.failed += 1;
+                println!("{label}: PANIC ({})", verification.actual);
+            }
+            Outcome::Missing => {
+                self.missing += 1;
+                println!("{label}: missing (got {})", verification.actual);
+            }
+        }
+    }
+
+    fn record_error(&mut self, day: u8, error: &str) {
+        self.failed += 1;
+        println!("day {day}: ERROR ({error})");
+    }
+
+    fn finish(self) -> Result<(), String> {
+        println!(
+            "{} passed, {} failed, {} missing",
+            self.passed, self.failed, self.missing
+        );
+
+        match self.failed {
+            0 => Ok(()),
+            failed => Err(format!("{failed} check(s) failed")),
+        }
+    }
+}
+
+pub fn verify(days: &Days, answers: Option<&Path>) -> Result<(), String> {
+    let registrations = select(days)?;
+    let resolver = InputResolver::from_env();
+
+    let sheet = resolver
+        .resolve_answers(answers, ANSWERS)
+        .map_err(|error| error.to_string())?;
+    let sheet = AnswerSheet::from_string(&sheet).map_err(|error| error.to_string())?;
+
+    let mut tally = Tally::default();
+
+    for registration in registrations {
+        let report = resolver
+            .resolve(registration.day, &InputSource::Default, registration.input)
+            .map_err(|error| error.to_string())
+            .and_then(|input| {
+                registration
+                    .solution
+                    .run(&input, &[Part::One, Part::Two])
+                    .map_err(|error| error.to_string())
+            });
+
+        match report {
+            Ok(report) => {
+                for verification in verify_report(registration.day, &report, &sheet) {
+                    tally.record(verification, "");
+                }
+            }
+            Err(error) => tally.record_error(registration.day, &error),
+        }
+    }
+
+    tally.finish()
+}
+
+pub fn verify_examples(days: &Days) -> Result<(), String> {
+    let mut tally = Tally::default();
+
+    for registration in select(days)? {
+        for example in registration.examples {
+            match verify_example(registration, example) {
+                Ok(verifications) => {
+                    for verification in verifications {
+                        tally.record(verification, &format!(" ({})", example.name));
+                    }
+                }
+                Err(error) => tally.record_error(registration.day, &error),
+            }
+        }
+    }
+
+    tally.finish()
+}
@@ -0,0 +1,182 @@
+use super::{
+    dial::{Counting, Dial},
+    direction::Direction,
+};
+use crate::util::Arithmetic;
+
+// A dial started at `s` is always the dial started at zero shifted by `s`, so the offsets of a
+// single dial from zero decide the zeroes counted from every start position.
+pub struct StartAnalysis {
+    zeroes: Vec<u64>,
+}
+
+impl StartAnalysis {
+    pub fn new(size: u64, directions: &[Direction], counting: Counting) -> Self {
+        let zeroes = match counting {
+            Counting::LandOn => landings(size, directions),
+            Counting::PassThrough => passes(size, directions),
+            Counting::Custom(_) => (0..size)
+                .map(|start| {
+                    let mut dial = Dial::new(size, start, counting);
+                    dial.move_by_directions(directions);
+                    dial.zeroes
+                })
+                .collect(),
+        };
+
+        Self { zeroes }
+    }
+}
+
+impl StartAnalysis {
+    pub fn zeroes(&self) -> &[u64] {
+        &self.zeroes
+    }
+
+    pub fn most_zeroes(&self) -> (u64, Vec<u64>) {
+        let most = self.zeroes.iter().copied().max().unwrap_or_default();
+
+        (most, self.starts_with(most))
+    }
+
+    pub fn starts_with(&self, zeroes: u64) -> Vec<u64> {
+        (0..)
+            .zip(&self.zeroes)
+            .filter(|(_, count)| **count == zeroes)
+            .map(|(start, _)| start)
+            .collect()
+    }
+}
+
+fn landings(size: u64, directions: &[Direction]) -> Vec<u64> {
+    let mut zeroes = vec![0; size as usize];
+    let mut origin = Dial::new(size, 0, Counting::LandOn);
+
+    for (index, direction) in directions.iter().enumerate() {
+        origin.move_by(*direction, index + 1);
+        zeroes[((size - origin.position) % size) as usize] += 1;
+    }
+
+    zeroes
+}
+
+// Full turns pass zero from every start, the remaining steps only from a cyclic range of starts.
+fn passes(size: u64, directions: &[Direction]) -> Vec<u64> {
+    let mut differences = vec![0_i64; size as usize + 1];
+    let mut full_turns = 0_u64;
+    let mut origin = Dial::new(size, 0, Counting::LandOn);
+
+    for (index, direction) in directions.iter().enumerate() {
+        let (Direction::Left(steps) | Direction::Right(steps)) = *direction;
+        let remainder = steps % size;
+
+        full_turns = full_turns.plus(steps / size);
+
+        if remainder > 0 {
+            let first = match direction {
+                Direction::Left(_) => 1,
+                Direction::Right(_) => size - remainder,
+            };
+
+            let start = (first + size - origin.position) % size;
+            let end = start + remainder;
+
+            differences[start as usize] += 1;
+
+            match end <= size {
+                true => differences[end as usize] -= 1,
+                false => {
+                    differences[size as usize] -= 1;
+                    differences[0] += 1;
+                    differences[(end - size) as usize] -= 1;
+                }
+            }
+        }
+
+        origin.move_by(*direction, index + 1);
+    }
+
+    let mut passes = 0;
+
+    differences[..size as usize]
+        .iter()
+        .map(|difference| {
+            passes += difference;
+            full_turns.plus(passes as u64)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::Random;
+
+    fn simulate(size: u64, directions: &[Direction], counting: Counting) -> Vec<u64> {
+        (0..size)
+            .map(|start| {
+                let mut dial = Dial::new(size, start, counting);
+                dial.move_by_directions(directions);
+                dial.zeroes
+            })
+            .collect()
+    }
+
+    #[test]
+    fn analyses_readme_sequence() {
+        let directions = Direction::many_from_string(include_str!("examples/example.txt")).unwrap();
+
+        let landings = StartAnalysis::new(100, &directions, Counting::LandOn);
+        let passes = StartAnalysis::new(100, &directions, Counting::PassThrough);
+
+        assert_eq!(landings.zeroes()[50], 3);
+        assert_eq!(passes.zeroes()[50], 6);
+        assert_eq!(
+            landings.zeroes(),
+            simulate(100, &directions, Counting::LandOn)
+        );
+        assert_eq!(
+            passes.zeroes(),
+            simulate(100, &directions, Counting::PassThrough)
+        );
+    }
+
+    #[test]
+    fn finds_best_and_exact_starts() {
+        let directions = Direction::many_from_string("R3\nL5\nR250").unwrap();
+
+        let landings = StartAnalysis::new(10, &directions, Counting::LandOn);
+        assert_eq!(landings.zeroes(), [0, 0, 2, 0, 0, 0, 0, 1, 0, 0]);
+        assert_eq!(landings.most_zeroes(), (2, vec![2]));
+        assert_eq!(landings.starts_with(1), vec![7]);
+        assert_eq!(landings.starts_with(3), vec![]);
+
+        let passes = StartAnalysis::new(10, &directions, Counting::PassThrough);
+        assert_eq!(passes.zeroes(), [26, 26, 26, 25, 25, 25, 25, 26, 27, 27]);
+        assert_eq!(passes.most_zeroes(), (27, vec![8, 9]));
+        assert_eq!(passes.starts_with(26), vec![0, 1, 2, 7]);
+    }
+
+    #[test]
+    fn matches_simulation_from_every_start() {
+        let mut random = Random(0x9e37_79b9_7f4a_7c15);
+
+        for _ in 0..200 {
+            let size = random.next(30) + 1;
+
+            let directions = Direction::many_from_random(&mut random, 20, 4 * size);
+
+            for counting in [
+                Counting::LandOn,
+                Counting::PassThrough,
+                Counting::Custom(|position| position == 0),
+            ] {
+                assert_eq!(
+                    StartAnalysis::new(size, &directions, counting).zeroes(),
+                    simulate(size, &directions, counting),
+                    "size {size}, {counting:?}"
+                );
+            }
+        }
+    }
+}
@@ -1,78 +1,280 @@
-use super::direction::Direction;
+use super::{direction::Direction, trace::TraceStep};
+use crate::util::Arithmetic;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Counting {
+    LandOn,
+    PassThrough,
+    #[allow(
+        dead_code,
+        reason = "only constructed by tests that compare against a custom predicate"
+    )]
+    Custom(fn(u64) -> bool),
+}
 
 pub struct Dial {
-    pub position: i32,
-    pub zeroes: u32,
+    size: u64,
+    counting: Counting,
+    pub position: u64,
+    pub zeroes: u64,
+    trace: Option<Vec<TraceStep>>,
 }
 
 impl Dial {
-    pub fn new() -> Self {
+    pub fn new(size: u64, start: u64, counting: Counting) -> Self {
         Self {
-            position: 50,
+            size,
+            counting,
+            position: start % size,
             zeroes: 0,
+            trace: None,
         }
     }
+
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
 }
 
 impl Dial {
+    #[cfg(test)]
     pub fn move_sequence(&mut self, sequence: &str) {
-        sequence.lines().for_each(|line| {
-            self.move_by_input(line);
-        });
+        self.move_by_directions(&Direction::many_from_string(sequence).unwrap());
     }
 
-    fn move_by_input(&mut self, input: &str) {
-        let direction = Direction::from_string(input);
-        self.move_by(direction);
+    pub fn move_by_directions(&mut self, directions: &[Direction]) {
+        directions
+            .iter()
+            .enumerate()
+            .for_each(|(index, direction)| {
+                self.move_by(*direction, index + 1);
+            });
     }
 
-    fn move_by(&mut self, direction: Direction) {
-        let current = self.position;
+    pub fn trace(&self) -> Option<&[TraceStep]> {
+        self.trace.as_deref()
+    }
 
-        let destination = match direction {
-            Direction::Left(steps) => {
-                let result = (current - steps) % 100;
+    pub fn move_by(&mut self, direction: Direction, line: usize) {
+        let start = self.position;
 
-                match result > 0 {
-                    true => result,
-                    false => 100 + result,
-                }
-            }
-            Direction::Right(steps) => (current + steps) % 100,
+        let zeroes = match self.counting {
+            Counting::LandOn => u64::from(self.destination(direction) == 0),
+            Counting::PassThrough => self.passed_zeroes(direction),
+            Counting::Custom(predicate) => self.matching_clicks(direction, predicate),
         };
 
-        if destination == 0 || destination == 100 {
-            self.position = 0;
-            self.zeroes += 1;
-            return;
+        self.zeroes = self.zeroes.plus(zeroes);
+        self.position = self.destination(direction);
+
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(TraceStep {
+                line,
+                direction,
+                start,
+                end: self.position,
+                zeroes,
+                total: self.zeroes,
+            });
+        }
+    }
+
+    fn destination(&self, direction: Direction) -> u64 {
+        match direction {
+            Direction::Left(steps) => (self.position + self.size - steps % self.size) % self.size,
+            Direction::Right(steps) => (self.position + steps % self.size) % self.size,
         }
+    }
+
+    // Turning left from a position passes zero as often as turning right from its mirror image.
+    fn passed_zeroes(&self, direction: Direction) -> u64 {
+        let (distance, steps) = match direction {
+            Direction::Left(steps) => ((self.size - self.position) % self.size, steps),
+            Direction::Right(steps) => (self.position, steps),
+        };
 
-        self.position = destination;
+        ((u128::from(distance) + u128::from(steps)) / u128::from(self.size)) as u64
+    }
+
+    fn matching_clicks(&self, direction: Direction, predicate: fn(u64) -> bool) -> u64 {
+        let mut position = self.position;
+        let mut matches = 0;
+
+        let (Direction::Left(steps) | Direction::Right(steps)) = direction;
+
+        for _ in 0..steps {
+            position = match direction {
+                Direction::Left(_) => (position + self.size - 1) % self.size,
+                Direction::Right(_) => (position + 1) % self.size,
+            };
+
+            if predicate(position) {
+                matches += 1;
+            }
+        }
+
+        matches
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::util::Random;
+
+    fn standard_dial(counting: Counting) -> Dial {
+        Dial::new(100, 50, counting)
+    }
 
     #[test]
     fn moves_dial_by_sequence() {
         let sequence = "L123\nR50\nL40";
 
-        let mut dial = Dial::new();
+        let mut dial = standard_dial(Counting::LandOn);
         dial.move_sequence(sequence);
 
         assert_eq!(dial.position, 37);
     }
 
     #[test]
-    fn moves_dial_by_readme_sequence() {
-        let sequence = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+    fn counts_zero_landings_in_readme_sequence() {
+        let sequence = include_str!("examples/example.txt");
 
-        let mut dial = Dial::new();
+        let mut dial = standard_dial(Counting::LandOn);
         dial.move_sequence(sequence);
 
         assert_eq!(dial.position, 32);
         assert_eq!(dial.zeroes, 3);
     }
+
+    #[test]
+    fn traverses_zero_right() {
+        let mut dial = standard_dial(Counting::PassThrough);
+        dial.move_sequence("R550\nR701");
+
+        assert_eq!(dial.position, 1);
+        assert_eq!(dial.zeroes, 13);
+    }
+
+    #[test]
+    fn traverses_zero_left() {
+        let mut dial = standard_dial(Counting::PassThrough);
+        dial.move_sequence("L550\nL701");
+
+        assert_eq!(dial.position, 99);
+        assert_eq!(dial.zeroes, 13);
+    }
+
+    #[test]
+    fn counts_zero_passes_in_readme_sequence() {
+        let sequence = include_str!("examples/example.txt");
+
+        let mut dial = standard_dial(Counting::PassThrough);
+        dial.move_sequence(sequence);
+
+        assert_eq!(dial.position, 32);
+        assert_eq!(dial.zeroes, 6);
+    }
+
+    #[test]
+    fn counts_clicks_matching_custom_predicate() {
+        let sequence = include_str!("examples/example.txt");
+
+        let mut dial = standard_dial(Counting::Custom(|position| position == 0));
+        dial.move_sequence(sequence);
+        assert_eq!(dial.zeroes, 6);
+
+        let mut dial = standard_dial(Counting::Custom(|position| position % 25 == 0));
+        dial.move_sequence("R30\nL60");
+        assert_eq!(dial.zeroes, 4);
+    }
+
+    #[test]
+    fn counts_full_turns_from_zero() {
+        let mut dial = Dial::new(100, 0, Counting::PassThrough);
+        dial.move_sequence("R100\nL200\nR0\nL0\nR1\nL1");
+
+        assert_eq!(dial.position, 0);
+        assert_eq!(dial.zeroes, 4);
+    }
+
+    #[test]
+    fn handles_large_rotations() {
+        let mut dial = standard_dial(Counting::PassThrough);
+        dial.move_sequence("R9999999999");
+
+        assert_eq!(dial.position, 49);
+        assert_eq!(dial.zeroes, 100_000_000);
+
+        let mut dial = standard_dial(Counting::PassThrough);
+        dial.move_sequence("L9999999999");
+
+        assert_eq!(dial.position, 51);
+        assert_eq!(dial.zeroes, 100_000_000);
+
+        let mut dial = Dial::new(7, 3, Counting::PassThrough);
+        dial.move_by_directions(&[Direction::Right(u64::MAX), Direction::Left(u64::MAX)]);
+
+        assert_eq!(dial.position, 3);
+        assert_eq!(dial.zeroes, 2 * ((u64::MAX - 4) / 7 + 1));
+    }
+
+    #[test]
+    fn matches_click_by_click_simulation() {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..500 {
+            let size = random.next(30) + 1;
+            let start = random.next(size);
+
+            let directions = Direction::many_from_random(&mut random, 20, 4 * size);
+
+            let mut landings = Dial::new(size, start, Counting::LandOn);
+            let mut passes = Dial::new(size, start, Counting::PassThrough);
+            let mut clicks = Dial::new(size, start, Counting::Custom(|position| position == 0));
+
+            for direction in directions {
+                landings.move_by_directions(&[direction]);
+                passes.move_by_directions(&[direction]);
+                clicks.move_by_directions(&[direction]);
+
+                assert_eq!(passes.zeroes, clicks.zeroes, "size {size}, {direction:?}");
+                assert_eq!(landings.position, clicks.position);
+                assert_eq!(passes.position, clicks.position);
+            }
+        }
+    }
+
+    #[test]
+    fn records_trace_per_rotation() {
+        let dial = standard_dial(Counting::PassThrough);
+        assert_eq!(dial.trace(), None);
+
+        let mut dial = dial.with_trace();
+        dial.move_sequence("L68\nR18\nR5");
+
+        let steps: Vec<(usize, u64, u64, u64, u64)> = dial
+            .trace()
+            .unwrap()
+            .iter()
+            .map(|step| (step.line, step.start, step.end, step.zeroes, step.total))
+            .collect();
+
+        assert_eq!(
+            steps,
+            vec![(1, 50, 82, 1, 1), (2, 82, 0, 1, 2), (3, 0, 5, 0, 2)]
+        );
+    }
+
+    #[test]
+    fn configures_size_and_start() {
+        let mut dial = Dial::new(10, 13, Counting::LandOn);
+        assert_eq!(dial.position, 3);
+
+        dial.move_sequence("R7\nL25\nR5");
+
+        assert_eq!(dial.position, 0);
+        assert_eq!(dial.zeroes, 2);
+    }
 }
@@ -1,24 +1,107 @@
-#[derive(Debug, PartialEq)]
+#[cfg(test)]
+use crate::util::Random;
+use crate::util::{ParseError, parse::integer};
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Direction {
-    Left(i32),
-    Right(i32),
+    Left(u64),
+    Right(u64),
 }
 
-impl Direction {
-    pub fn from_string(input: &str) -> Self {
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut chars = input.chars();
 
-        let first = chars.nth(0).unwrap();
-        let number_part: String = chars.collect();
-        let steps = number_part.parse::<i32>().unwrap();
+        let first = chars
+            .next()
+            .ok_or_else(|| ParseError::new("expected a rotation like L68"))?;
+
+        let direction: fn(u64) -> Self = match first {
+            'L' | '-' => Self::Left,
+            'R' | '+' => Self::Right,
+            _ => {
+                return Err(ParseError::new(format!(
+                    "unknown rotation '{first}', expected L, R, - or +"
+                ))
+                .at_column(1));
+            }
+        };
+
+        let steps = chars.as_str();
+        let prefix = first.len_utf8();
+
+        if !steps.starts_with(|character: char| character.is_ascii_digit()) {
+            return Err(ParseError::new("expected a number of steps").at_column(prefix + 1));
+        }
+
+        if let Some(index) = steps.find(|character: char| !character.is_ascii_digit()) {
+            return Err(ParseError::new("expected a digit").at_column(prefix + index + 1));
+        }
+
+        let steps =
+            integer("a number of steps")(steps).map_err(|error| error.shift_column(prefix))?;
+
+        Ok(direction(steps))
+    }
+}
 
-        match first {
-            'L' => Self::Left(steps),
-            _ => Self::Right(steps),
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Left(steps) => write!(f, "L{steps}"),
+            Direction::Right(steps) => write!(f, "R{steps}"),
         }
     }
 }
 
+impl Direction {
+    pub fn many_from_string(input: &str) -> Result<Vec<Self>, ParseError> {
+        let directions = Self::numbered_from_string(input)?;
+
+        Ok(directions
+            .into_iter()
+            .map(|(_, direction)| direction)
+            .collect())
+    }
+
+    pub fn numbered_from_string(input: &str) -> Result<Vec<(usize, Self)>, ParseError> {
+        let mut directions = vec![];
+
+        for (index, line) in input.lines().enumerate() {
+            let content = line.split('#').next().unwrap_or_default();
+            let rotation = content.trim();
+
+            if rotation.is_empty() {
+                continue;
+            }
+
+            let indentation = content.len() - content.trim_start().len();
+            let direction = rotation
+                .parse()
+                .map_err(|error: ParseError| error.shift_column(indentation).at_line(index + 1))?;
+
+            directions.push((index + 1, direction));
+        }
+
+        Ok(directions)
+    }
+}
+
+#[cfg(test)]
+impl Direction {
+    pub fn many_from_random(random: &mut Random, count: usize, bound: u64) -> Vec<Self> {
+        (0..count)
+            .map(|_| match random.next(2) {
+                0 => Direction::Left(random.next(bound)),
+                _ => Direction::Right(random.next(bound)),
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -26,8 +109,69 @@ mod test {
     #[test]
     fn constructs_direction() {
         let input = "L123";
-        let direction = Direction::from_string(input);
+        let direction = input.parse::<Direction>();
+
+        assert_eq!(direction, Ok(Direction::Left(123)));
+    }
+
+    #[test]
+    fn reports_malformed_directions() {
+        let result = Direction::many_from_string("L68\nR\nL5");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 2: expected a number of steps"
+        );
+
+        let result = Direction::many_from_string("L68\nX20\nL5");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 1: unknown rotation 'X', expected L, R, - or +"
+        );
+
+        let result = Direction::many_from_string("L68\n\n  l20 # lowercase");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 3, column 3: unknown rotation 'l', expected L, R, - or +"
+        );
+
+        let result = Direction::many_from_string("R+5");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 2: expected a number of steps"
+        );
+
+        let result = Direction::many_from_string("L5x");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 1, column 3: expected a digit"
+        );
+
+        let result = Direction::many_from_string("R10\n  +123 4 # gap");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 2, column 7: expected a digit"
+        );
+    }
+
+    #[test]
+    fn accepts_signed_and_zero_step_rotations() {
+        assert_eq!("+14".parse(), Ok(Direction::Right(14)));
+        assert_eq!("-5".parse(), Ok(Direction::Left(5)));
+        assert_eq!("L0".parse(), Ok(Direction::Left(0)));
+        assert_eq!("R0".parse(), Ok(Direction::Right(0)));
+    }
+
+    #[test]
+    fn skips_blank_lines_and_comments() {
+        let input = "# rotations\nL68\n\n  R14  # right\n   \n-3";
 
-        assert_eq!(direction, Direction::Left(123));
+        assert_eq!(
+            Direction::numbered_from_string(input),
+            Ok(vec![
+                (2, Direction::Left(68)),
+                (4, Direction::Right(14)),
+                (6, Direction::Left(3)),
+            ])
+        );
     }
 }
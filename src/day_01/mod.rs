@@ -1,24 +1,73 @@
+mod analysis;
 mod dial;
-mod dial_traverse;
 mod direction;
+mod trace;
 
-use crate::{day_01::dial_traverse::TraverseZeroDial, util::read_input};
-use dial::Dial;
+use crate::{
+    solution::{Answer, Example, Part, Solution},
+    util::ParseError,
+};
+use analysis::StartAnalysis;
+use dial::{Counting, Dial};
+use trace::TraceStep;
 
-pub fn solve_part_1() -> u32 {
-    let sequence = read_input("src/day_01/input.txt");
+use direction::Direction;
+pub use trace::{render_csv, render_json, render_table};
 
-    let mut dial = Dial::new();
-    dial.move_sequence(&sequence);
+const DIAL_SIZE: u64 = 100;
+const DIAL_START: u64 = 50;
 
-    dial.zeroes
+pub const INPUT: &str = include_str!("input.txt");
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Model = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        Direction::many_from_string(input)
+    }
+
+    fn solve_part_1(directions: &Self::Model) -> Answer {
+        let mut dial = Dial::new(DIAL_SIZE, DIAL_START, Counting::LandOn);
+        dial.move_by_directions(directions);
+
+        dial.zeroes.into()
+    }
+
+    fn solve_part_2(directions: &Self::Model) -> Answer {
+        let mut dial = Dial::new(DIAL_SIZE, DIAL_START, Counting::PassThrough);
+        dial.move_by_directions(directions);
+
+        dial.zeroes.into()
+    }
+}
+
+pub fn trace(input: &str, part: Part) -> Result<Vec<TraceStep>, ParseError> {
+    let mut dial = Dial::new(DIAL_SIZE, DIAL_START, counting(part)).with_trace();
+
+    for (line, direction) in Direction::numbered_from_string(input)? {
+        dial.move_by(direction, line);
+    }
+
+    Ok(dial.trace().unwrap_or_default().to_vec())
 }
 
-pub fn solve_part_2() -> u32 {
-    let sequence = read_input("src/day_01/input.txt");
+pub fn analyse_starts(input: &str, part: Part) -> Result<StartAnalysis, ParseError> {
+    let directions = Direction::many_from_string(input)?;
 
-    let mut dial = TraverseZeroDial::new();
-    dial.move_sequence(&sequence);
+    Ok(StartAnalysis::new(DIAL_SIZE, &directions, counting(part)))
+}
 
-    dial.zeroes
+fn counting(part: Part) -> Counting {
+    match part {
+        Part::One => Counting::LandOn,
+        Part::Two => Counting::PassThrough,
+    }
 }
@@ -0,0 +1,118 @@
+use super::direction::Direction;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TraceStep {
+    pub line: usize,
+    pub direction: Direction,
+    pub start: u64,
+    pub end: u64,
+    pub zeroes: u64,
+    pub total: u64,
+}
+
+pub fn render_table(steps: &[TraceStep]) -> String {
+    let mut lines = vec![format!(
+        "{:>5}  {:<9}  {:>5}  {:>5}  {:>6}  {:>6}",
+        "line", "rotation", "start", "end", "zeroes", "total"
+    )];
+
+    for step in steps {
+        lines.push(format!(
+            "{:>5}  {:<9}  {:>5}  {:>5}  {:>6}  {:>6}",
+            step.line,
+            step.direction.to_string(),
+            step.start,
+            step.end,
+            step.zeroes,
+            step.total
+        ));
+    }
+
+    lines.join("\n")
+}
+
+pub fn render_csv(steps: &[TraceStep]) -> String {
+    let mut lines = vec![String::from("line,rotation,start,end,zeroes,total")];
+
+    for step in steps {
+        lines.push(format!(
+            "{},{},{},{},{},{}",
+            step.line, step.direction, step.start, step.end, step.zeroes, step.total
+        ));
+    }
+
+    lines.join("\n")
+}
+
+pub fn render_json(steps: &[TraceStep]) -> String {
+    let steps: Vec<String> = steps
+        .iter()
+        .map(|step| {
+            format!(
+                "{{\"line\":{},\"rotation\":\"{}\",\"start\":{},\"end\":{},\"zeroes\":{},\"total\":{}}}",
+                step.line, step.direction, step.start, step.end, step.zeroes, step.total
+            )
+        })
+        .collect();
+
+    format!("{{\"steps\":[{}]}}", steps.join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<TraceStep> {
+        vec![
+            TraceStep {
+                line: 1,
+                direction: Direction::Left(68),
+                start: 50,
+                end: 82,
+                zeroes: 1,
+                total: 1,
+            },
+            TraceStep {
+                line: 2,
+                direction: Direction::Right(18),
+                start: 82,
+                end: 0,
+                zeroes: 1,
+                total: 2,
+            },
+        ]
+    }
+
+    #[test]
+    fn renders_table() {
+        assert_eq!(
+            render_table(&example()),
+            [
+                " line  rotation   start    end  zeroes   total",
+                "    1  L68           50     82       1       1",
+                "    2  R18           82      0       1       2",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn renders_csv() {
+        assert_eq!(
+            render_csv(&example()),
+            "line,rotation,start,end,zeroes,total\n1,L68,50,82,1,1\n2,R18,82,0,1,2"
+        );
+    }
+
+    #[test]
+    fn renders_json() {
+        assert_eq!(
+            render_json(&example()),
+            concat!(
+                "{\"steps\":[",
+                "{\"line\":1,\"rotation\":\"L68\",\"start\":50,\"end\":82,\"zeroes\":1,\"total\":1},",
+                "{\"line\":2,\"rotation\":\"R18\",\"start\":82,\"end\":0,\"zeroes\":1,\"total\":2}]}"
+            )
+        );
+    }
+}
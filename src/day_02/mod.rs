@@ -1,18 +1,33 @@
 mod range;
 
-use crate::util::read_input;
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::ParseError,
+};
 use range::Range;
 
-pub fn solve_part_1() -> u64 {
-    let input = read_input("src/day_02/input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-    let ranges = Range::many_from_string(&input);
-    Range::sum_duplicate_once_ids_in_ranges(&ranges)
-}
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Model = Vec<Range>;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        Range::many_from_string(input)
+    }
 
-pub fn solve_part_2() -> u64 {
-    let input = read_input("src/day_02/input.txt");
+    fn solve_part_1(ranges: &Self::Model) -> Answer {
+        Range::sum_duplicate_once_ids_in_ranges(ranges).into()
+    }
 
-    let ranges = Range::many_from_string(&input);
-    Range::sum_duplicate_at_least_once_ids_in_ranges(&ranges)
+    fn solve_part_2(ranges: &Self::Model) -> Answer {
+        Range::sum_duplicate_at_least_once_ids_in_ranges(ranges).into()
+    }
 }
@@ -1,49 +1,66 @@
+use crate::util::{
+    Interval, ParseError,
+    parse::{integer, lines, pair, separated},
+    sum,
+};
+use std::str::FromStr;
+
 pub struct Range {
-    start: u64,
-    end: u64,
+    ids: Interval<u64>,
 }
 
 impl Range {
     fn new(start: u64, end: u64) -> Self {
-        Self { start, end }
+        Self {
+            ids: Interval::new(start, end),
+        }
     }
 
-    pub fn from_string(input: &str) -> Self {
-        let mut entries = input.split("-");
+    pub fn many_from_string(input: &str) -> Result<Vec<Self>, ParseError> {
+        let ranges = lines(separated(",", str::parse))(input)?;
 
-        let start = entries.nth(0).unwrap().parse::<u64>().unwrap();
-        let end = entries.nth(0).unwrap().parse::<u64>().unwrap();
-
-        Self::new(start, end)
+        Ok(ranges.into_iter().flatten().collect())
     }
+}
 
-    pub fn many_from_string(input: &str) -> Vec<Self> {
-        input.split(",").map(Self::from_string).collect()
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (start_id, end_id) = pair(
+            "-",
+            "a range like 11-22",
+            integer("a start id"),
+            integer("an end id"),
+        )(input)?;
+
+        if start_id > end_id {
+            return Err(ParseError::new("range start must not exceed its end"));
+        }
+
+        Ok(Self::new(start_id, end_id))
     }
 }
 
 impl Range {
     pub fn sum_duplicate_once_ids(&self) -> u64 {
-        let range = self.start..=self.end;
-
-        range.filter(|entry| is_id_duplicate_once(*entry)).sum()
+        sum(self.ids().filter(|entry| is_id_duplicate_once(*entry)))
     }
 
     pub fn sum_duplicate_at_least_once_ids(&self) -> u64 {
-        let range = self.start..=self.end;
+        sum(self.ids().filter(|entry| is_id_duplicate(*entry)))
+    }
 
-        range.filter(|entry| is_id_duplicate(*entry)).sum()
+    fn ids(&self) -> impl Iterator<Item = u64> {
+        self.ids.start..=self.ids.end
     }
 
     pub fn sum_duplicate_once_ids_in_ranges(ranges: &[Range]) -> u64 {
-        ranges.iter().map(Range::sum_duplicate_once_ids).sum()
+        sum(ranges.iter().map(Range::sum_duplicate_once_ids))
     }
 
     pub fn sum_duplicate_at_least_once_ids_in_ranges(ranges: &[Range]) -> u64 {
-        ranges
-            .iter()
-            .map(Range::sum_duplicate_at_least_once_ids)
-            .sum()
+        sum(ranges.iter().map(Range::sum_duplicate_at_least_once_ids))
     }
 }
 
@@ -102,19 +119,36 @@ mod test {
 
     #[test]
     fn determines_if_id_is_duplicate_once() {
-        assert_eq!(is_id_duplicate_once(1212), true);
-        assert_eq!(is_id_duplicate_once(1122), false);
+        assert!(is_id_duplicate_once(1212));
+        assert!(!is_id_duplicate_once(1122));
     }
 
     #[test]
     fn determines_if_id_is_duplicate_at_least_once() {
-        assert_eq!(is_id_duplicate(1212), true);
+        assert!(is_id_duplicate(1212));
+    }
+
+    #[test]
+    fn reports_malformed_ranges() {
+        let result = Range::many_from_string("11-22,95-x,998-1012");
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 1, column 10: expected an end id"))
+        );
+
+        let result = Range::many_from_string("11-22,95115");
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from(
+                "line 1, column 7: expected a range like 11-22"
+            ))
+        );
     }
 
     #[test]
     fn counts_duplicate_ids() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        let ranges = Range::many_from_string(input);
+        let input = include_str!("examples/example.txt");
+        let ranges = Range::many_from_string(input).unwrap();
 
         let result = Range::sum_duplicate_once_ids_in_ranges(&ranges);
         assert_eq!(result, 1227775554);
@@ -122,8 +156,8 @@ mod test {
 
     #[test]
     fn counts_ids_duplicated_at_least_once() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        let ranges = Range::many_from_string(input);
+        let input = include_str!("examples/example.txt");
+        let ranges = Range::many_from_string(input).unwrap();
 
         let result = Range::sum_duplicate_at_least_once_ids_in_ranges(&ranges);
         assert_eq!(result, 4174379265);
@@ -1,16 +1,31 @@
+use crate::util::{ParseError, parse::lines, sum};
+use std::str::FromStr;
+
 pub struct BatteryBank {
     batteries: String,
 }
 
-impl BatteryBank {
-    pub fn from_string(input: &str) -> Self {
-        Self {
-            batteries: input.to_string(),
+impl FromStr for BatteryBank {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.is_empty() {
+            return Err(ParseError::new("expected a bank of batteries"));
         }
+
+        if let Some(index) = input.find(|character: char| !character.is_ascii_digit()) {
+            return Err(ParseError::new("expected a battery joltage digit").at_column(index + 1));
+        }
+
+        Ok(Self {
+            batteries: input.to_string(),
+        })
     }
+}
 
-    pub fn multiple_from_string(input: String) -> Vec<Self> {
-        input.lines().map(BatteryBank::from_string).collect()
+impl BatteryBank {
+    pub fn multiple_from_string(input: &str) -> Result<Vec<Self>, ParseError> {
+        lines(str::parse)(input)
     }
 }
 
@@ -23,18 +38,14 @@ impl BatteryBank {
         find_maximum_joltage(&bank.batteries, batteries)
     }
 
-    pub fn best_joltages(banks: &Vec<BatteryBank>, batteries: usize) -> u128 {
-        banks
+    pub fn best_joltages(banks: &[BatteryBank], batteries: usize) -> u128 {
+        sum(banks
             .iter()
-            .map(|bank| BatteryBank::best_joltage(bank, batteries))
-            .sum()
+            .map(|bank| BatteryBank::best_joltage(bank, batteries)))
     }
 
-    pub fn best_joltages_two_batteries(banks: &Vec<BatteryBank>) -> u128 {
-        banks
-            .iter()
-            .map(BatteryBank::best_joltage_two_batteries)
-            .sum()
+    pub fn best_joltages_two_batteries(banks: &[BatteryBank]) -> u128 {
+        sum(banks.iter().map(BatteryBank::best_joltage_two_batteries))
     }
 }
 
@@ -79,6 +90,18 @@ fn find_highest_digit(numbers: &str) -> (usize, u8) {
 mod test {
     use super::*;
 
+    #[test]
+    fn rejects_non_digit_batteries() {
+        let result = BatteryBank::multiple_from_string("987654321111111\n81111a111111119");
+
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from(
+                "line 2, column 6: expected a battery joltage digit"
+            ))
+        );
+    }
+
     #[test]
     fn finds_maximum_joltage() {
         let result = find_maximum_joltage("987654321111111", 2);
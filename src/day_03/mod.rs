@@ -1,18 +1,33 @@
 mod battery;
 
-use crate::util::read_input;
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::ParseError,
+};
 use battery::BatteryBank;
 
-pub fn solve_part_1() -> u128 {
-    let input = read_input("src/day_03/input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-    let banks = BatteryBank::multiple_from_string(input);
-    BatteryBank::best_joltages_two_batteries(&banks)
-}
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Model = Vec<BatteryBank>;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        BatteryBank::multiple_from_string(input)
+    }
 
-pub fn solve_part_2() -> u128 {
-    let input = read_input("src/day_03/input.txt");
+    fn solve_part_1(banks: &Self::Model) -> Answer {
+        BatteryBank::best_joltages_two_batteries(banks).into()
+    }
 
-    let banks = BatteryBank::multiple_from_string(input);
-    BatteryBank::best_joltages(&banks, 12)
+    fn solve_part_2(banks: &Self::Model) -> Answer {
+        BatteryBank::best_joltages(banks, 12).into()
+    }
 }
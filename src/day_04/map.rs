@@ -1,40 +1,31 @@
-use crate::util::Position2D;
-use std::collections::HashMap;
+use crate::util::{Grid, ParseError, Position2D};
 
+#[derive(Clone)]
 pub struct Map {
-    occupied: HashMap<Position2D, bool>,
+    rolls: Grid<bool>,
 }
 
 impl Map {
-    pub fn from_string(input: &str) -> Self {
-        let mut occupied = HashMap::new();
-
-        input.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, character)| {
-                if character == '@' {
-                    occupied.insert((x, y), true);
-                }
-            });
-        });
-
-        Self { occupied }
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
+        let rolls = Grid::from_string(input, |character| match character {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Self { rolls })
     }
 }
 
 impl Map {
     pub fn rolls_with_max_neighbors(&self, max: usize) -> usize {
-        self.occupied
-            .iter()
-            .filter(|(position, _)| self.occupied_neighbors(position) < max)
-            .count()
+        self.accessible_rolls(max).len()
     }
 
-    pub fn occupied_neighbors(&self, position: &Position2D) -> usize {
-        let neighbors = neighboring_positions(position);
-
-        neighbors
-            .iter()
-            .filter(|position| self.occupied.contains_key(position))
+    pub fn occupied_neighbors(&self, position: Position2D) -> usize {
+        self.rolls
+            .neighbors_with_diagonals(position)
+            .filter(|neighbor| self.rolls.get(*neighbor) == Some(&true))
             .count()
     }
 
@@ -54,46 +45,24 @@ impl Map {
     }
 
     pub fn remove_accessible_rolls(&mut self, max: usize) -> usize {
-        let to_remove: Vec<Position2D> = self
-            .occupied
-            .iter()
-            .filter_map(|(position, _)| {
-                if self.occupied_neighbors(position) < max {
-                    return Some(*position);
-                }
-
-                None
-            })
-            .collect();
+        let to_remove = self.accessible_rolls(max);
 
         to_remove.iter().for_each(|position| {
-            self.occupied.remove(position);
+            if let Some(roll) = self.rolls.get_mut(*position) {
+                *roll = false;
+            }
         });
 
         to_remove.len()
     }
-}
-
-fn neighboring_positions(position: &Position2D) -> Vec<Position2D> {
-    let (x, y) = *position;
-
-    let mut result = vec![(x, y + 1), (x + 1, y), (x + 1, y + 1)];
-
-    if x > 0 {
-        result.push((x - 1, y));
-        result.push((x - 1, y + 1));
-    }
 
-    if y > 0 {
-        result.push((x, y - 1));
-        result.push((x + 1, y - 1));
-    }
-
-    if y > 0 && x > 0 {
-        result.push((x - 1, y - 1));
+    fn accessible_rolls(&self, max: usize) -> Vec<Position2D> {
+        self.rolls
+            .iter()
+            .filter(|(position, roll)| **roll && self.occupied_neighbors(*position) < max)
+            .map(|(position, _)| position)
+            .collect()
     }
-
-    result
 }
 
 #[cfg(test)]
@@ -102,9 +71,9 @@ mod test {
 
     #[test]
     fn finds_paper_rolls_with_max_neighbors() {
-        let input = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
+        let input = include_str!("examples/example.txt");
 
-        let map = Map::from_string(input);
+        let map = Map::from_string(input).unwrap();
         let result = map.rolls_with_max_neighbors(4);
 
         assert_eq!(result, 13);
@@ -112,11 +81,21 @@ mod test {
 
     #[test]
     fn removes_all_accessible_rolls() {
-        let input = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
+        let input = include_str!("examples/example.txt");
 
-        let mut map = Map::from_string(input);
+        let mut map = Map::from_string(input).unwrap();
         let result = map.remove_all_accessible_rolls(4);
 
         assert_eq!(result, 43);
     }
+
+    #[test]
+    fn rejects_unknown_cells() {
+        let result = Map::from_string("..@\n.x@");
+
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2, column 2: unexpected character 'x'"))
+        );
+    }
 }
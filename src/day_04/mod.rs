@@ -1,18 +1,34 @@
 mod map;
 
-use crate::util::read_input;
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::ParseError,
+};
 use map::Map;
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_04/input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-    let map = Map::from_string(&input);
-    map.rolls_with_max_neighbors(4)
-}
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Model = Map;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        Map::from_string(input)
+    }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_04/input.txt");
+    fn solve_part_1(map: &Self::Model) -> Answer {
+        map.rolls_with_max_neighbors(4).into()
+    }
 
-    let mut map = Map::from_string(&input);
-    map.remove_all_accessible_rolls(4)
+    fn solve_part_2(map: &Self::Model) -> Answer {
+        let mut map = map.clone();
+        map.remove_all_accessible_rolls(4).into()
+    }
 }
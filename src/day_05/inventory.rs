@@ -1,161 +1,102 @@
-#[derive(PartialEq, Debug, Clone, Copy)]
-pub struct Range {
-    start: usize,
-    end: usize,
+use crate::util::{
+    Interval, IntervalSet, ParseError,
+    parse::{integer, lines, pair, sections},
+};
+use std::str::FromStr;
+
+fn parse_range(input: &str) -> Result<Interval<usize>, ParseError> {
+    let (start, end) = pair(
+        "-",
+        "an id range like 3-5",
+        integer("an ingredient id"),
+        integer("an ingredient id"),
+    )(input)?;
+
+    Ok(Interval::new(start, end))
 }
 
-impl Range {
-    fn from_string(input: &str) -> Self {
-        let mut parts = input.split("-");
-
-        Self {
-            start: to_number(parts.next()),
-            end: to_number(parts.next()),
-        }
-    }
-}
-
-fn to_number(input: Option<&str>) -> usize {
-    input.unwrap().parse().unwrap()
-}
-
-impl Range {
-    fn is_in_range(&self, number: usize) -> bool {
-        number >= self.start && number <= self.end
-    }
-
-    fn size(&self) -> usize {
-        self.end - self.start + 1
-    }
-
-    fn conflate_with(&self, other: &Range) -> Option<Range> {
-        if self.start > other.end || other.start > self.end {
-            return None;
-        }
-
-        if self.start < other.start && self.end > other.end {
-            return Some(*self);
-        }
-
-        if other.start < self.start && other.end > self.end {
-            return Some(*other);
-        }
-
-        let start = std::cmp::min(self.start, other.start);
-        let end = std::cmp::max(self.end, other.end);
-
-        Some(Range { start, end })
-    }
+pub struct Inventory {
+    ranges: IntervalSet<usize>,
 }
 
-fn ranges_from_string(input: &str) -> Vec<Range> {
-    input.lines().map(Range::from_string).collect()
-}
+impl FromStr for Inventory {
+    type Err = ParseError;
 
-pub struct Inventory {
-    ranges: Vec<Range>,
-}
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let ranges = lines(parse_range)(input)?;
 
-impl Inventory {
-    fn from_string(input: &str) -> Self {
-        Self {
-            ranges: ranges_from_string(input),
-        }
+        Ok(Self {
+            ranges: ranges.into_iter().collect(),
+        })
     }
 }
 
 impl Inventory {
     fn is_included(&self, number: usize) -> bool {
-        self.ranges.iter().any(|range| range.is_in_range(number))
+        self.ranges.contains(number)
     }
 
-    pub fn total_indices(mut self) -> usize {
-        self = self.conflate_ranges();
-
-        self.ranges.iter().fold(0, |sum, next| sum + next.size())
+    pub fn total_indices(&self) -> u128 {
+        self.ranges.length()
     }
+}
 
-    fn conflate_ranges(self) -> Self {
-        let mut ranges: Vec<Range> = vec![];
-        let mut pool = self.ranges;
-
-        while let Some(first) = pool.pop() {
-            let mut to_add = vec![];
-            let mut was_conflated = false;
-
-            dbg!(&first);
-
-            while let Some(second) = pool.pop() {
-                dbg!(&second);
-                let conflated = first.conflate_with(&second);
-
-                dbg!(&conflated);
-
-                match conflated {
-                    None => {
-                        to_add.push(second);
-                    }
-                    Some(new) => {
-                        was_conflated = true;
-                        pool.push(new);
-                        break;
-                    }
-                }
-            }
-
-            if !was_conflated {
-                ranges.push(first);
-            }
+pub struct Database {
+    pub inventory: Inventory,
+    ingredients: Vec<usize>,
+}
 
-            pool.append(&mut to_add);
+impl FromStr for Database {
+    type Err = ParseError;
 
-            dbg!(&pool, &ranges);
-        }
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (inventory, ingredients) = sections(
+            "a blank line between id ranges and ingredient ids",
+            str::parse,
+            lines(integer("an ingredient id")),
+        )(input)?;
 
-        Self { ranges }
+        Ok(Self {
+            inventory,
+            ingredients,
+        })
     }
 }
 
-pub fn inventory_from_string(input: &str) -> Inventory {
-    let mut parts = input.split("\n\n");
-
-    Inventory::from_string(parts.next().unwrap())
-}
-
-pub fn fresh_parts_from_string(input: &str) -> usize {
-    let mut parts = input.split("\n\n");
-
-    let inventory = Inventory::from_string(parts.next().unwrap());
-
-    parts
-        .next()
-        .unwrap()
-        .lines()
-        .map(|number| number.parse::<usize>().unwrap())
-        .filter(|number| inventory.is_included(*number))
-        .count()
+impl Database {
+    pub fn fresh_ingredients(&self) -> usize {
+        self.ingredients
+            .iter()
+            .filter(|number| self.inventory.is_included(**number))
+            .count()
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    fn inventory_from_string(input: &str) -> Inventory {
+        input.parse().unwrap()
+    }
+
     #[test]
     fn conflates_inventory_ranges() {
         let input = "3-5\n10-14\n16-20\n12-18";
         let inventory = inventory_from_string(input);
 
-        let conflated = inventory.conflate_ranges();
         assert_eq!(
-            conflated.ranges,
-            vec![Range::from_string("10-20"), Range::from_string("3-5")]
+            inventory.ranges.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(3, 5), Interval::new(10, 20)]
         );
 
         let input = "1-1000\n80-200\n210-300\n200-600\n900-1452";
         let inventory = inventory_from_string(input);
 
-        let conflated = inventory.conflate_ranges();
-        assert_eq!(conflated.ranges, vec![Range::from_string("1-1452")]);
+        assert_eq!(
+            inventory.ranges.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(1, 1452)]
+        );
     }
 
     #[test]
@@ -174,8 +115,33 @@ mod test {
     }
 
     #[test]
-    fn finds_range_size() {
-        let range = Range::from_string("3-5");
-        assert_eq!(range.size(), 3);
+    fn counts_fresh_ingredients() {
+        let input = include_str!("examples/example.txt");
+        let database: Database = input.parse().unwrap();
+
+        assert_eq!(database.fresh_ingredients(), 3);
+    }
+
+    #[test]
+    fn reports_malformed_database() {
+        let result = "3-5\n10-14\n\n1\nfive".parse::<Database>();
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 5, column 1: expected an ingredient id"))
+        );
+
+        let result = "3-5\n10_14\n\n1".parse::<Database>();
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2: expected an id range like 3-5"))
+        );
+
+        let result = "3-5\n1".parse::<Database>();
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from(
+                "expected a blank line between id ranges and ingredient ids"
+            ))
+        );
     }
 }
@@ -1,17 +1,33 @@
 mod inventory;
 
-use crate::{day_05::inventory::inventory_from_string, util::read_input};
-use inventory::fresh_parts_from_string;
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::ParseError,
+};
+use inventory::Database;
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_05/input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-    fresh_parts_from_string(&input)
-}
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Model = Database;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        input.parse()
+    }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_05/input.txt");
+    fn solve_part_1(database: &Self::Model) -> Answer {
+        database.fresh_ingredients().into()
+    }
 
-    let inventory = inventory_from_string(&input);
-    inventory.total_indices()
+    fn solve_part_2(database: &Self::Model) -> Answer {
+        database.inventory.total_indices().into()
+    }
 }
@@ -1,3 +1,8 @@
+use crate::util::{
+    BigUint, ParseError,
+    parse::{columns, lines},
+};
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum Operator {
     Multiply,
@@ -7,125 +12,180 @@ enum Operator {
 #[derive(PartialEq, Debug)]
 pub struct Assignment {
     operator: Operator,
-    numbers: Vec<String>,
+    numbers: Vec<BigUint>,
+    cephalopod_numbers: Vec<BigUint>,
 }
 
 impl Assignment {
-    pub fn multiple_from_string(input: &str) -> Vec<Self> {
-        let mut result = vec![];
-
+    pub fn multiple_from_string(input: &str) -> Result<Vec<Self>, ParseError> {
         let mut operators: Vec<(usize, Operator)> = vec![];
 
-        let operators_line = input.lines().last().unwrap();
+        let total_lines = input.lines().count();
+        let operators_line = input
+            .lines()
+            .last()
+            .ok_or_else(|| ParseError::new("expected a line of operators"))?;
+
+        for (index, character) in operators_line.chars().enumerate() {
+            match character {
+                '*' => operators.push((index, Operator::Multiply)),
+                '+' => operators.push((index, Operator::Add)),
+                ' ' => {}
+                _ => {
+                    return Err(ParseError::new("expected '*' or '+'")
+                        .at_line(total_lines)
+                        .at_column(index + 1));
+                }
+            };
+        }
+
+        if total_lines < 2 {
+            return Err(ParseError::new("expected numbers above the operators"));
+        }
+
+        if operators.is_empty() {
+            return Err(ParseError::new("expected at least one operator").at_line(total_lines));
+        }
 
-        operators_line
-            .chars()
+        let ranges = operators
+            .iter()
             .enumerate()
-            .for_each(|(index, character)| {
-                match character {
-                    '*' => operators.push((index, Operator::Multiply)),
-                    '+' => operators.push((index, Operator::Add)),
-                    _ => {}
-                };
-            });
+            .map(|(index, (start, _))| {
+                let end = operators
+                    .get(index + 1)
+                    .map_or(operators_line.len(), |(next, _)| next - 1);
 
-        let lines = input.lines();
-        let total_lines = &lines.count();
+                *start..end
+            })
+            .collect();
 
-        let default = (operators_line.len() + 1, Operator::Add);
+        let numbers: Vec<&str> = input.lines().take(total_lines - 1).collect();
+        let rows = lines(columns(ranges, parse_number))(&numbers.join("\n"))?;
 
         operators
             .iter()
             .enumerate()
-            .for_each(|(operator_index, (start_index, operator))| {
-                let mut numbers: Vec<String> = vec![];
+            .map(|(index, (start, operator))| {
+                let cells: Vec<&str> = rows.iter().map(|row| row[index].0.as_str()).collect();
 
-                let (end_index, _) = operators.get(operator_index + 1).unwrap_or(&default);
+                Ok(Assignment {
+                    operator: *operator,
+                    numbers: rows.iter().map(|row| row[index].1.clone()).collect(),
+                    cephalopod_numbers: parse_cephalopod_numbers(&cells)
+                        .map_err(|error| error.shift_column(*start))?,
+                })
+            })
+            .collect()
+    }
+}
 
-                input.lines().take(total_lines - 1).for_each(|line| {
-                    let number: String = line
-                        .chars()
-                        .skip(*start_index)
-                        .take(*end_index - start_index - 1)
-                        .collect();
+fn parse_number(cell: &str) -> Result<(String, BigUint), ParseError> {
+    if let Some(index) =
+        cell.find(|character: char| !character.is_ascii_digit() && character != ' ')
+    {
+        return Err(ParseError::new("expected a digit").at_column(index + 1));
+    }
 
-                    numbers.push(number);
-                });
+    let number = cell.trim();
+    let indentation = cell.len() - cell.trim_start().len();
 
-                result.push(Assignment {
-                    operator: *operator,
-                    numbers,
-                });
-            });
+    if number.is_empty() {
+        return Err(ParseError::new("expected a number").at_column(1));
+    }
 
-        result
+    if let Some(index) = number.find(' ') {
+        return Err(ParseError::new("expected a digit").at_column(indentation + index + 1));
     }
+
+    let value = number
+        .parse()
+        .map_err(|error: ParseError| error.shift_column(indentation))?;
+
+    Ok((cell.to_string(), value))
 }
 
-impl Assignment {
-    fn result(&self) -> u64 {
-        result_with_operator(
-            &self
-                .numbers
-                .iter()
-                .map(|entry| entry.trim().parse().unwrap())
-                .collect::<Vec<u64>>(),
-            &self.operator,
-        )
-    }
+// Cephalopods write their numbers top to bottom, one number per column of the cells.
+fn parse_cephalopod_numbers(cells: &[&str]) -> Result<Vec<BigUint>, ParseError> {
+    let width = cells
+        .iter()
+        .map(|cell| cell.len())
+        .max()
+        .unwrap_or_default();
+    let mut numbers = vec![];
 
-    fn cephalopod_result(&self) -> u64 {
-        let as_text: Vec<Vec<char>> = self
-            .numbers
+    for column in 0..width {
+        let digits: String = cells
             .iter()
-            .map(|number| number.to_string().chars().collect())
+            .map(|cell| {
+                cell.as_bytes()
+                    .get(column)
+                    .map_or(' ', |digit| char::from(*digit))
+            })
             .collect();
 
-        let max_length = as_text
-            .iter()
-            .fold(0, |result, next| std::cmp::max(result, next.len()));
-
-        let mut numbers: Vec<u64> = vec![];
+        let number = digits.trim();
 
-        for index in 0..max_length {
-            let mut number = String::new();
+        if number.is_empty() {
+            continue;
+        }
 
-            for entry in as_text.iter() {
-                let digit = entry.get(index);
-                match digit {
-                    None => {}
-                    Some(digit) => number.push(*digit),
-                }
-            }
+        if let Some(index) = number.find(' ') {
+            let line = digits.len() - digits.trim_start().len() + index + 1;
 
-            numbers.push(number.trim().parse().unwrap());
+            return Err(ParseError::new("expected a digit")
+                .at_line(line)
+                .at_column(column + 1));
         }
 
-        result_with_operator(&numbers, &self.operator)
+        numbers.push(number.parse()?);
     }
+
+    Ok(numbers)
 }
 
-fn result_with_operator(numbers: &[u64], operator: &Operator) -> u64 {
-    let start = numbers.first().unwrap();
+impl Assignment {
+    fn result(&self) -> BigUint {
+        result_with_operator(&self.numbers, &self.operator)
+    }
 
-    numbers
-        .iter()
-        .skip(1)
-        .fold(*start, |result, next| match operator {
-            Operator::Add => result + next,
-            _ => result * next,
-        })
+    fn cephalopod_result(&self) -> BigUint {
+        result_with_operator(&self.cephalopod_numbers, &self.operator)
+    }
 }
 
-pub fn assignment_results_from_string(input: &str) -> u64 {
-    let assignments = Assignment::multiple_from_string(input);
+fn result_with_operator(numbers: &[BigUint], operator: &Operator) -> BigUint {
+    match small_result_with_operator(numbers, operator) {
+        Some(result) => result.into(),
+        None => {
+            let (first, rest) = numbers.split_first().unwrap();
+
+            rest.iter()
+                .fold(first.clone(), |result, next| match operator {
+                    Operator::Add => result + next,
+                    _ => &result * next,
+                })
+        }
+    }
+}
 
-    assignments.iter().map(Assignment::result).sum()
+fn small_result_with_operator(numbers: &[BigUint], operator: &Operator) -> Option<u128> {
+    let (first, rest) = numbers.split_first()?;
+
+    rest.iter().try_fold(first.to_u128()?, |result, next| {
+        let next = next.to_u128()?;
+
+        match operator {
+            Operator::Add => result.checked_add(next),
+            _ => result.checked_mul(next),
+        }
+    })
 }
 
-pub fn cephalopod_assignment_results_from_string(input: &str) -> u64 {
-    let assignments = Assignment::multiple_from_string(input);
+pub fn assignment_results(assignments: &[Assignment]) -> BigUint {
+    assignments.iter().map(Assignment::result).sum()
+}
 
+pub fn cephalopod_assignment_results(assignments: &[Assignment]) -> BigUint {
     assignments.iter().map(Assignment::cephalopod_result).sum()
 }
 
@@ -133,67 +193,125 @@ pub fn cephalopod_assignment_results_from_string(input: &str) -> u64 {
 mod test {
     use super::*;
 
+    fn numbers(values: &[u64]) -> Vec<BigUint> {
+        values.iter().map(|value| BigUint::from(*value)).collect()
+    }
+
     #[test]
     fn parses_assignments_from_text() {
         let expected = vec![
             Assignment {
                 operator: Operator::Multiply,
-                numbers: vec![
-                    String::from("123"),
-                    String::from(" 45"),
-                    String::from("  6"),
-                ],
+                numbers: numbers(&[123, 45, 6]),
+                cephalopod_numbers: numbers(&[1, 24, 356]),
             },
             Assignment {
                 operator: Operator::Add,
-                numbers: vec![
-                    String::from("328"),
-                    String::from("64 "),
-                    String::from("98 "),
-                ],
+                numbers: numbers(&[328, 64, 98]),
+                cephalopod_numbers: numbers(&[369, 248, 8]),
             },
             Assignment {
                 operator: Operator::Multiply,
-                numbers: vec![
-                    String::from(" 51"),
-                    String::from("387"),
-                    String::from("215"),
-                ],
+                numbers: numbers(&[51, 387, 215]),
+                cephalopod_numbers: numbers(&[32, 581, 175]),
             },
             Assignment {
                 operator: Operator::Add,
-                numbers: vec![
-                    String::from("64 "),
-                    String::from("23 "),
-                    String::from("314"),
-                ],
+                numbers: numbers(&[64, 23, 314]),
+                cephalopod_numbers: numbers(&[623, 431, 4]),
             },
         ];
 
-        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+        let input = include_str!("examples/example.txt");
         let result = Assignment::multiple_from_string(input);
-        assert_eq!(&result, &expected);
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn reports_malformed_assignments() {
+        let result = Assignment::multiple_from_string("12 3\n4x 5\n*  +");
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2, column 2: expected a digit"))
+        );
+
+        let result = Assignment::multiple_from_string("12 3\n45 6\n*  -");
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 3, column 4: expected '*' or '+'"))
+        );
+
+        let result = Assignment::multiple_from_string("123 4\n4 5 6\n*   +");
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2, column 2: expected a digit"))
+        );
+
+        let result = Assignment::multiple_from_string("13\n 6\n28\n* ");
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2, column 1: expected a digit"))
+        );
     }
 
     #[test]
     fn calculates_result_from_string() {
-        let input = "123 328 51  64 \n45  64  387 23 \n6   98  215 314\n*   +   *   +  ";
+        let input = include_str!("examples/example.txt");
 
-        let result = assignment_results_from_string(input);
+        let assignments = Assignment::multiple_from_string(input).unwrap();
+        let result = assignment_results(&assignments);
 
-        assert_eq!(result, 4277556);
+        assert_eq!(result, BigUint::from(4277556_u64));
     }
 
     #[test]
     fn calculates_cephalopod_result_from_string() {
         let input = "64 \n23 \n314\n+  ";
 
-        let result = cephalopod_assignment_results_from_string(input);
-        assert_eq!(result, 1058);
+        let assignments = Assignment::multiple_from_string(input).unwrap();
+        let result = cephalopod_assignment_results(&assignments);
+        assert_eq!(result, BigUint::from(1058_u64));
 
         let input = " 51\n387\n215\n*  ";
 
-        let result = cephalopod_assignment_results_from_string(input);
-        assert_eq!(result, 3253600);
+        let assignments = Assignment::multiple_from_string(input).unwrap();
+        let result = cephalopod_assignment_results(&assignments);
+        assert_eq!(result, BigUint::from(3253600_u64));
+    }
+
+    #[test]
+    fn calculates_results_beyond_u128() {
+        let number = "9999999999999999999";
+        let input = format!(
+            "{number}\n{number}\n{number}\n*{}",
+            " ".repeat(number.len() - 1)
+        );
+
+        let assignments = Assignment::multiple_from_string(&input).unwrap();
+        let result = assignment_results(&assignments);
+
+        assert_eq!(
+            result.to_string(),
+            "999999999999999999700000000000000000029999999999999999999"
+        );
+    }
+
+    #[test]
+    fn calculates_results_with_operands_beyond_u64() {
+        let input = "99999999999999999999\n2                   \n*                   ";
+
+        let assignments = Assignment::multiple_from_string(input).unwrap();
+
+        assert_eq!(
+            assignment_results(&assignments).to_string(),
+            "199999999999999999998"
+        );
+
+        let number = "1".repeat(40);
+        let input = format!("{number}\n{number}\n+{}", " ".repeat(number.len() - 1));
+
+        let assignments = Assignment::multiple_from_string(&input).unwrap();
+
+        assert_eq!(assignment_results(&assignments).to_string(), "2".repeat(40));
     }
 }
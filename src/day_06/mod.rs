@@ -1,16 +1,33 @@
 mod assignment;
 
-use crate::util::read_input;
-use assignment::{assignment_results_from_string, cephalopod_assignment_results_from_string};
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::ParseError,
+};
+use assignment::{Assignment, assignment_results, cephalopod_assignment_results};
 
-pub fn solve_part_1() -> u64 {
-    let input = read_input("src/day_06/input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-    assignment_results_from_string(&input)
-}
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Model = Vec<Assignment>;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        Assignment::multiple_from_string(input)
+    }
 
-pub fn solve_part_2() -> u64 {
-    let input = read_input("src/day_06/input.txt");
+    fn solve_part_1(assignments: &Self::Model) -> Answer {
+        assignment_results(assignments).into()
+    }
 
-    cephalopod_assignment_results_from_string(&input)
+    fn solve_part_2(assignments: &Self::Model) -> Answer {
+        cephalopod_assignment_results(assignments).into()
+    }
 }
@@ -1,16 +1,33 @@
 mod splitter;
 
-use crate::util::read_input;
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::ParseError,
+};
 use splitter::Map2D;
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_07/input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-    Map2D::from_string(&input).count_splits()
-}
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Model = Map2D;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        input.parse()
+    }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_07/input.txt");
+    fn solve_part_1(map: &Self::Model) -> Answer {
+        map.count_splits().into()
+    }
 
-    Map2D::from_string(&input).count_timelines()
+    fn solve_part_2(map: &Self::Model) -> Answer {
+        map.count_timelines().into()
+    }
 }
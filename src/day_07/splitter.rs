@@ -1,146 +1,103 @@
-use crate::util::Position2D;
-use std::collections::{HashMap, HashSet};
+use crate::util::{BigUint, Grid, ParseError, Position2D, Vector2D, bfs, count_paths};
+use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Map2D {
-    splitters: HashMap<Position2D, usize>,
-    beams: HashSet<Position2D>,
-
-    x_max: usize,
-    y_max: usize,
+    next_splitters: Grid<Option<usize>>,
+    start: Position2D,
 }
 
-impl Map2D {
-    pub fn from_string(input: &str) -> Self {
-        let mut splitters = HashMap::new();
-        let mut beams = HashSet::new();
-
-        let mut x_max = 0;
-        let mut y_max = 0;
-
-        input.lines().enumerate().for_each(|(y, line)| {
-            y_max = y;
-
-            line.chars().enumerate().for_each(|(x, character)| {
-                x_max = x;
-
-                match character {
-                    '^' => {
-                        splitters.insert((x, y), 0);
-                    }
-                    'S' => {
-                        beams.insert((x, y));
-                    }
-                    _ => {}
-                };
-            });
-        });
-
-        Self {
-            splitters,
-            beams,
-            x_max,
-            y_max,
-        }
-    }
-}
+impl FromStr for Map2D {
+    type Err = ParseError;
 
-impl Map2D {
-    pub fn count_splits(mut self) -> usize {
-        let mut splits = 0;
-
-        while !self.beams.is_empty() {
-            let mut entries: Vec<Position2D> = self.beams.drain().collect();
-
-            while let Some(beam) = entries.pop() {
-                let (x, y) = beam;
-                let new_y = y + 1;
-                let new_position = (x, new_y);
-
-                if new_position.1 > self.y_max || new_position.0 > self.x_max {
-                    continue;
-                }
-
-                let next = self.splitters.get(&new_position);
-
-                match next {
-                    None => {
-                        self.beams.insert(new_position);
-                    }
-                    Some(_) => {
-                        splits += 1;
-
-                        if x > 0 {
-                            let left = (x - 1, new_y);
-                            self.beams.insert(left);
-                        }
-
-                        let right = (x + 1, new_y);
-                        self.beams.insert(right);
-                    }
-                }
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut start = None;
+
+        let splitters = Grid::from_string(input, |character| match character {
+            '^' => Some(true),
+            '.' | 'S' => Some(false),
+            _ => None,
+        })?;
+
+        for (y, line) in input.lines().enumerate() {
+            if let Some(x) = line.find('S') {
+                start.get_or_insert(Position2D::new(x, y));
             }
         }
 
-        splits
+        let start = start.ok_or_else(|| ParseError::new("expected a beam start 'S'"))?;
+        let next_splitters = next_splitters(&splitters);
+
+        Ok(Self {
+            next_splitters,
+            start,
+        })
     }
+}
+
+// For every cell, the row of the first splitter at or below it in the same column.
+fn next_splitters(splitters: &Grid<bool>) -> Grid<Option<usize>> {
+    let mut next_splitters = splitters.map(|_| None);
 
-    fn find_splitter(&self, start: &Position2D) -> Option<(Position2D, usize)> {
-        let result = None;
+    for y in (0..splitters.height()).rev() {
+        for x in 0..splitters.width() {
+            let position = Position2D::new(x, y);
 
-        for y in start.1..self.y_max {
-            let position = (start.0, y);
-            let splitter = self.splitters.get(&position);
+            let next = match splitters.get(position) {
+                Some(true) => Some(y),
+                _ => next_splitters
+                    .get(Position2D::new(x, y + 1))
+                    .copied()
+                    .flatten(),
+            };
 
-            if splitter.is_some() {
-                return splitter.map(|value| (position, *value));
+            if let Some(cell) = next_splitters.get_mut(position) {
+                *cell = next;
             }
         }
-
-        result
     }
 
-    pub fn count_timelines(mut self) -> usize {
-        let beam = self.beams.iter().next().unwrap();
-        let first_splitter = self.find_splitter(beam);
+    next_splitters
+}
 
-        match first_splitter {
-            None => None,
-            Some((position, _)) => self.splitters.insert(position, 1),
+impl Map2D {
+    pub fn count_splits(&self) -> usize {
+        let Some(first_splitter) = self.find_splitter(self.start) else {
+            return 0;
         };
 
-        for y in 0..self.y_max {
-            for x in 0..self.x_max {
-                let position = (x, y);
-                let splitter = self.splitters.get(&position);
-
-                if splitter.is_none() {
-                    continue;
-                }
-
-                let total = *splitter.unwrap_or(&0);
-
-                let right = (x + 1, y + 1);
-                let next_right = self.find_splitter(&right);
+        let next_splitters = |splitter| {
+            self.split_beams(splitter)
+                .flatten()
+                .filter_map(|beam| self.find_splitter(beam))
+        };
 
-                let next_left = match x > 0 {
-                    true => self.find_splitter(&(x - 1, y + 1)),
-                    _ => None,
-                };
+        bfs(&next_splitters, [first_splitter]).len()
+    }
 
-                if let Some((position_right, value)) = next_right {
-                    self.splitters.insert(position_right, total + value);
-                }
+    pub fn count_timelines(&self) -> BigUint {
+        let successors =
+            |beam: Option<Position2D>| match beam.and_then(|beam| self.find_splitter(beam)) {
+                Some(splitter) => self.split_beams(splitter).collect(),
+                None => vec![None],
+            };
 
-                if let Some((position_left, value)) = next_left {
-                    self.splitters.insert(position_left, total + value);
-                }
-            }
-        }
+        count_paths(&successors, Some(self.start), |beam| beam.is_none()).unwrap()
+    }
 
-        let sum: usize = self.splitters.into_values().sum();
+    // Beams leaving the manifold to the left are None, like beams falling out of the bottom.
+    fn split_beams(&self, splitter: Position2D) -> impl Iterator<Item = Option<Position2D>> {
+        [Vector2D::new(-1, 1), Vector2D::new(1, 1)]
+            .into_iter()
+            .map(move |direction| splitter.checked_add(direction))
+    }
 
-        sum + 1
+    fn find_splitter(&self, beam: Position2D) -> Option<Position2D> {
+        self.next_splitters
+            .get(beam)
+            .copied()
+            .flatten()
+            .map(|y| Position2D::new(beam.x, y))
     }
 }
 
@@ -150,19 +107,42 @@ mod test {
 
     #[test]
     fn counts_map_splits() {
-        let input = ".......S.......\n...............\n.......^.......\n...............\n......^.^......\n...............\n.....^.^.^.....\n...............\n....^.^...^....\n...............\n...^.^...^.^...\n...............\n..^...^.....^..\n...............\n.^.^.^.^.^...^.\n...............";
+        let input = include_str!("examples/example.txt");
 
-        let map = Map2D::from_string(input);
+        let map: Map2D = input.parse().unwrap();
 
         assert_eq!(map.count_splits(), 21);
     }
 
+    #[test]
+    fn requires_beam_start() {
+        let result = "...\n.^.".parse::<Map2D>();
+
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("expected a beam start 'S'"))
+        );
+    }
+
     #[test]
     fn counts_map_timelines() {
-        let input = ".......S.......\n...............\n.......^.......\n...............\n......^.^......\n...............\n.....^.^.^.....\n...............\n....^.^...^....\n...............\n...^.^...^.^...\n...............\n..^...^.....^..\n...............\n.^.^.^.^.^...^.\n...............";
+        let input = include_str!("examples/example.txt");
+
+        let map: Map2D = input.parse().unwrap();
+
+        assert_eq!(map.count_timelines(), BigUint::from(40_u8));
+    }
+
+    #[test]
+    fn splits_beams_at_the_edges() {
+        let map: Map2D = "..S..\n..^..\n.^.^.\n".parse().unwrap();
+
+        assert_eq!(map.count_splits(), 3);
+        assert_eq!(map.count_timelines(), BigUint::from(4_u8));
 
-        let map = Map2D::from_string(input);
+        let map: Map2D = "S.\n^.\n.^".parse().unwrap();
 
-        assert_eq!(map.count_timelines(), 40);
+        assert_eq!(map.count_splits(), 2);
+        assert_eq!(map.count_timelines(), BigUint::from(3_u8));
     }
 }
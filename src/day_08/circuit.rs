@@ -1,205 +1,68 @@
-use std::{cmp::Ordering, collections::HashMap};
+use crate::util::{DisjointSet, ParseError, Point3, parse_lines};
+use std::str::FromStr;
 
-#[derive(Debug, Hash, PartialEq)]
-pub struct Junction {
-    pub x: u64,
-    y: u64,
-    z: u64,
-}
-
-fn to_coordinate(entry: Option<&str>) -> u64 {
-    entry.unwrap().parse().unwrap()
-}
-
-fn coordinate_square(a: u64, b: u64) -> u64 {
-    let a = a as i64;
-    let b = b as i64;
+fn sorted_connections(junctions: &[Point3]) -> Vec<(usize, usize)> {
+    let mut distances: Vec<(u128, (usize, usize))> = vec![];
 
-    (b - a).pow(2) as u64
-}
-
-fn sorted_identities(a: &str, b: &str) -> String {
-    let mut identities = vec![a, b];
-    identities.sort();
-
-    let mut iterator = identities.into_iter();
-    format!("{}-{}", iterator.next().unwrap(), iterator.next().unwrap())
-}
-
-impl Junction {
-    fn from_string(input: &str) -> Self {
-        let mut entries = input.split(',');
-
-        Self {
-            x: to_coordinate(entries.next()),
-            y: to_coordinate(entries.next()),
-            z: to_coordinate(entries.next()),
+    for (index, junction) in junctions.iter().enumerate() {
+        for (other_index, other) in junctions.iter().enumerate().skip(index + 1) {
+            distances.push((junction.squared_distance(other), (index, other_index)));
         }
     }
 
-    fn identity(&self) -> String {
-        format!("{},{},{}", self.x, self.y, self.z)
-    }
+    distances.sort_by_key(|(distance, _)| *distance);
 
-    pub fn many_from_string(input: &str) -> Vec<Self> {
-        input.lines().map(Self::from_string).collect()
-    }
+    distances.into_iter().map(|(_, pair)| pair).collect()
 }
 
-impl Junction {
-    fn distance_to(&self, other: &Self) -> f64 {
-        let squared = coordinate_square(self.x, other.x)
-            + coordinate_square(self.y, other.y)
-            + coordinate_square(self.z, other.z);
-
-        squared as f64
-    }
+pub struct Playground {
+    junctions: Vec<Point3>,
+    connections: Vec<(usize, usize)>,
 }
 
-fn junction_distances(junctions: &[Junction]) -> HashMap<String, f64> {
-    let mut distances: HashMap<String, f64> = HashMap::new();
-
-    for junction in junctions.iter() {
-        let identity = junction.identity();
+impl FromStr for Playground {
+    type Err = ParseError;
 
-        for other in junctions.iter() {
-            let other_identity = other.identity();
-
-            if identity == other_identity {
-                continue;
-            }
-
-            let key = sorted_identities(&identity, &other_identity);
-
-            if distances.contains_key(&key) {
-                continue;
-            }
-
-            let distance = junction.distance_to(other);
-            distances.insert(key, distance);
-        }
-    }
-
-    distances
-}
-
-fn connect_junction<'a>(
-    key: &'a str,
-    circuits: &mut HashMap<String, u64>,
-    next_circuit: &mut u64,
-) -> (&'a str, &'a str) {
-    let mut parts = key.split('-');
-    let key_1 = parts.next().unwrap();
-    let key_2 = parts.next().unwrap();
-
-    let circuit_1 = circuits.get(key_1);
-    let circuit_2 = circuits.get(key_2);
-
-    if circuit_1.is_some() && circuit_2.is_some() {
-        let circuit_1 = *circuit_1.unwrap();
-        let circuit_2 = *circuit_2.unwrap();
-
-        let found: Vec<String> = circuits
-            .iter()
-            .filter_map(|(key, circuit)| match circuit == &circuit_2 {
-                true => Some(key.to_string()),
-                false => None,
-            })
-            .collect();
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let junctions: Vec<Point3> = parse_lines(input, str::parse)?;
 
-        for key in found {
-            circuits.insert(key.clone(), circuit_1);
+        if junctions.len() < 2 {
+            return Err(ParseError::new("expected at least two junction boxes"));
         }
 
-        return (key_1, key_2);
-    }
-
-    let target_circuit;
+        let connections = sorted_connections(&junctions);
 
-    if circuit_1.is_none() && circuit_2.is_none() {
-        target_circuit = *next_circuit;
-        *next_circuit += 1;
-    } else {
-        target_circuit = *circuit_1.unwrap_or(circuit_2.unwrap_or(&0));
+        Ok(Self {
+            junctions,
+            connections,
+        })
     }
-
-    circuits.insert(key_1.to_string(), target_circuit);
-    circuits.insert(key_2.to_string(), target_circuit);
-
-    return (key_1, key_2);
 }
 
-pub fn connect_junctions(junctions: &Vec<Junction>, connections: usize) -> HashMap<String, u64> {
-    let distances = junction_distances(&junctions);
-    let mut distances: Vec<(String, f64)> = distances.into_iter().collect();
+pub fn connect_junctions(playground: &Playground, connections: usize) -> DisjointSet {
+    let mut circuits = DisjointSet::new(playground.junctions.len());
 
-    distances.sort_by(|(_, distance_a), (_, distance_b)| {
-        let result = distance_b.partial_cmp(distance_a);
-        result.unwrap_or(Ordering::Equal)
-    });
-
-    let mut circuits: HashMap<String, u64> = HashMap::new();
-    let mut next_circuit: u64 = 0;
-    let mut connected = 0;
-
-    while let Some((key, _)) = distances.pop()
-        && connected < connections
-    {
-        connected += 1;
-        connect_junction(&key, &mut circuits, &mut next_circuit);
+    for (a, b) in playground.connections.iter().take(connections) {
+        circuits.union(*a, *b);
     }
 
     circuits
 }
 
-pub fn find_global_connection_point(junctions: &Vec<Junction>) -> Option<(Junction, Junction)> {
-    let distances = junction_distances(&junctions);
-    let mut distances: Vec<(String, f64)> = distances.into_iter().collect();
-
-    distances.sort_by(|(_, distance_a), (_, distance_b)| {
-        let result = distance_b.partial_cmp(distance_a);
-        result.unwrap_or(Ordering::Equal)
-    });
-
-    let mut circuits: HashMap<String, u64> = HashMap::new();
-    let mut next_circuit: u64 = 0;
+pub fn find_global_connection_point(playground: &Playground) -> Option<(Point3, Point3)> {
+    let mut circuits = DisjointSet::new(playground.junctions.len());
 
-    while let Some((key, _)) = distances.pop() {
-        let (key_1, key_2) = connect_junction(&key, &mut circuits, &mut next_circuit);
-
-        if circuits.len() == junctions.len() {
-            return Some((Junction::from_string(key_1), Junction::from_string(key_2)));
+    for (a, b) in playground.connections.iter() {
+        if circuits.union(*a, *b) && circuits.component_count() == 1 {
+            return Some((playground.junctions[*a], playground.junctions[*b]));
         }
     }
 
     None
 }
 
-pub fn flatten_circuits(circuits: &HashMap<String, u64>) -> Vec<u64> {
-    let circuit_ids: Vec<&u64> = circuits.values().collect();
-
-    let mut circuit_lengths: HashMap<u64, u64> = HashMap::new();
-
-    for id in circuit_ids {
-        let mut count = 0;
-
-        for value in circuits.values() {
-            if value == id {
-                count += 1;
-            }
-        }
-
-        circuit_lengths.insert(*id, count);
-    }
-
-    let mut entries = circuit_lengths
-        .values()
-        .map(|value| *value)
-        .collect::<Vec<u64>>();
-
-    entries.sort_by(|a, b| b.cmp(a));
-
-    entries
+pub fn flatten_circuits(circuits: &DisjointSet) -> Vec<usize> {
+    circuits.component_sizes()
 }
 
 #[cfg(test)]
@@ -208,54 +71,86 @@ mod tests {
 
     #[test]
     fn connects_junctions() {
-        let input = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689";
-
-        let junctions = Junction::many_from_string(input);
-        let result = connect_junctions(&junctions, 10);
+        let input = include_str!("examples/example.txt");
+
+        let playground: Playground = input.parse().unwrap();
+        let mut circuits = connect_junctions(&playground, 10);
+
+        let mut result: Vec<Vec<String>> = circuits
+            .components()
+            .into_iter()
+            .filter(|circuit| circuit.len() > 1)
+            .map(|circuit| {
+                let mut junctions: Vec<String> = circuit
+                    .iter()
+                    .map(|index| playground.junctions[*index].to_string())
+                    .collect();
+                junctions.sort();
+                junctions
+            })
+            .collect();
+        result.sort();
 
         assert_eq!(
             result,
-            HashMap::from([
-                ("425,690,689".to_string(), 0),
-                ("162,817,812".to_string(), 0),
-                ("346,949,466".to_string(), 0),
-                ("431,825,988".to_string(), 0),
-                ("862,61,35".to_string(), 1),
-                ("739,650,466".to_string(), 1),
-                ("805,96,715".to_string(), 1),
-                ("906,360,560".to_string(), 1),
-                ("984,92,344".to_string(), 1),
-                ("117,168,530".to_string(), 3),
-                ("52,470,668".to_string(), 3),
-                ("819,987,18".to_string(), 4),
-                ("941,993,340".to_string(), 4),
-            ])
+            vec![
+                vec!["117,168,530", "52,470,668"],
+                vec!["162,817,812", "346,949,466", "425,690,689", "431,825,988"],
+                vec![
+                    "739,650,466",
+                    "805,96,715",
+                    "862,61,35",
+                    "906,360,560",
+                    "984,92,344"
+                ],
+                vec!["819,987,18", "941,993,340"],
+            ]
         );
     }
 
     #[test]
     fn determines_circuit_length_distribution() {
-        let input = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689";
+        let input = include_str!("examples/example.txt");
 
-        let junctions = Junction::many_from_string(input);
-        let circuits = connect_junctions(&junctions, 10);
+        let playground: Playground = input.parse().unwrap();
+        let circuits = connect_junctions(&playground, 10);
         let lengths = flatten_circuits(&circuits);
 
-        assert_eq!(lengths, vec![5, 4, 2, 2]);
+        assert_eq!(lengths, vec![5, 4, 2, 2, 1, 1, 1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn reports_malformed_junctions() {
+        let result = "162,817,812\n57,618".parse::<Playground>();
+
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2: expected 3 comma-separated integers"))
+        );
+
+        for input in ["", "162,817,812"] {
+            assert_eq!(
+                input
+                    .parse::<Playground>()
+                    .err()
+                    .map(|error| error.to_string()),
+                Some(String::from("expected at least two junction boxes"))
+            );
+        }
     }
 
     #[test]
     fn determines_junction_connection_that_completes_global_circuit() {
-        let input = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689";
+        let input = include_str!("examples/example.txt");
 
-        let junctions = Junction::many_from_string(input);
-        let result = find_global_connection_point(&junctions);
+        let playground: Playground = input.parse().unwrap();
+        let result = find_global_connection_point(&playground);
 
         assert_eq!(
             result.unwrap(),
             (
-                Junction::from_string("117,168,530"),
-                Junction::from_string("216,146,977"),
+                "216,146,977".parse().unwrap(),
+                "117,168,530".parse().unwrap(),
             )
         );
     }
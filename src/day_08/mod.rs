@@ -1,33 +1,45 @@
 mod circuit;
 
-use crate::util::read_input;
-use circuit::{Junction, connect_junctions, find_global_connection_point, flatten_circuits};
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::{Arithmetic, ParseError, product},
+};
+use circuit::{Playground, connect_junctions, find_global_connection_point, flatten_circuits};
 
-pub fn solve_part_1() -> u64 {
-    let input = read_input("src/day_08/input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-    multiply_largest_circuits(&input, 3, 1000)
-}
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day08;
 
-pub fn solve_part_2() -> u64 {
-    let input = read_input("src/day_08/input.txt");
+impl Solution for Day08 {
+    type Model = Playground;
 
-    let junctions = Junction::many_from_string(&input);
-    let (junction_1, junction_2) = find_global_connection_point(&junctions).unwrap();
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        input.parse()
+    }
 
-    junction_1.x * junction_2.x
+    fn solve_part_1(playground: &Self::Model) -> Answer {
+        multiply_largest_circuits(playground, 3, 1000).into()
+    }
+
+    fn solve_part_2(playground: &Self::Model) -> Answer {
+        let (junction_1, junction_2) = find_global_connection_point(playground).unwrap();
+
+        junction_1.x.times(junction_2.x).into()
+    }
 }
 
-fn multiply_largest_circuits(input: &str, number: usize, connections: usize) -> u64 {
-    let junctions = Junction::many_from_string(input);
-    let circuits = connect_junctions(&junctions, connections);
+fn multiply_largest_circuits(playground: &Playground, number: usize, connections: usize) -> usize {
+    let circuits = connect_junctions(playground, connections);
 
     let circuit_lengths = flatten_circuits(&circuits);
 
-    circuit_lengths
-        .iter()
-        .take(number)
-        .fold(1, |result, next| result * next)
+    product(circuit_lengths.into_iter().take(number))
 }
 
 #[cfg(test)]
@@ -36,9 +48,10 @@ mod tests {
 
     #[test]
     fn multiplies_largest_circuit_groups() {
-        let input = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689";
+        let input = include_str!("examples/example.txt");
 
-        let result = multiply_largest_circuits(input, 3, 10);
+        let playground: Playground = input.parse().unwrap();
+        let result = multiply_largest_circuits(&playground, 3, 10);
         assert_eq!(result, 40)
     }
 }
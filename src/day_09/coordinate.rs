@@ -1,15 +1,52 @@
-use crate::util::Position2D;
+use crate::util::{
+    ParseError, Position2D,
+    parse::{fields, integer, lines},
+};
 
-fn to_coordinate(entry: Option<&str>) -> usize {
-    entry.unwrap().parse().unwrap()
+pub fn coordinates_from_string(input: &str) -> Result<Vec<Position2D>, ParseError> {
+    let coordinates = lines(|line| {
+        let [x, y] = fields(",", "2 comma-separated integers", integer("an integer"))(line)?;
+
+        Ok(Position2D::new(x, y))
+    })(input)?;
+
+    if coordinates.len() < 2 {
+        return Err(ParseError::new("expected at least two red tiles"));
+    }
+
+    Ok(coordinates)
 }
 
-pub fn coordinates_from_string(input: &str) -> Vec<Position2D> {
-    input
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(',');
-            (to_coordinate(parts.next()), to_coordinate(parts.next()))
-        })
-        .collect()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_coordinates() {
+        assert_eq!(
+            coordinates_from_string("7,1\n11,1"),
+            Ok(vec![Position2D::new(7, 1), Position2D::new(11, 1)])
+        );
+
+        let result = coordinates_from_string("7,1\n11;1");
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2, column 1: expected an integer"))
+        );
+
+        let result = coordinates_from_string("7,1\n11,1,4");
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2: expected 2 comma-separated integers"))
+        );
+
+        for input in ["", "7,1"] {
+            assert_eq!(
+                coordinates_from_string(input)
+                    .err()
+                    .map(|error| error.to_string()),
+                Some(String::from("expected at least two red tiles"))
+            );
+        }
+    }
 }
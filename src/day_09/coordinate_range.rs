@@ -1,4 +1,5 @@
 use super::util::find_min_max;
+use crate::util::{Position2D, Vector2D};
 
 #[derive(Debug, PartialEq)]
 pub enum Direction {
@@ -8,18 +9,32 @@ pub enum Direction {
     Down,
 }
 
+impl Direction {
+    pub fn vector(&self) -> Vector2D {
+        match self {
+            Direction::Left => Vector2D::LEFT,
+            Direction::Right => Vector2D::RIGHT,
+            Direction::Up => Vector2D::UP,
+            Direction::Down => Vector2D::DOWN,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy, Eq, PartialOrd, Hash)]
 pub struct CoordinateRange {
-    pub start: (usize, usize),
-    pub end: (usize, usize),
+    pub start: Position2D,
+    pub end: Position2D,
 }
 
 impl CoordinateRange {
-    pub fn new(start: (usize, usize), end: (usize, usize)) -> Self {
-        Self { start, end }
+    pub fn new(start: impl Into<Position2D>, end: impl Into<Position2D>) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+        }
     }
 
-    pub fn many_from_coordinates(coordinates: &[(usize, usize)]) -> Vec<Self> {
+    pub fn many_from_coordinates(coordinates: &[Position2D]) -> Vec<Self> {
         let mut ranges: Vec<CoordinateRange> = coordinates
             .iter()
             .enumerate()
@@ -41,35 +56,19 @@ impl CoordinateRange {
 
 impl CoordinateRange {
     fn overlaps_horizontally(&self, other: &CoordinateRange) -> bool {
-        let (start_self, end_self) = self.get_points();
-        let (start_other, end_other) = other.get_points();
+        let (x_min, x_max) = find_min_max(other.start.x, other.end.x);
 
-        let (x_start_self, _) = *start_self;
-        let (x_end_self, _) = *end_self;
-        let (x_start_other, _) = *start_other;
-        let (x_end_other, _) = *end_other;
-
-        let (x_min, x_max) = find_min_max(x_start_other, x_end_other);
-
-        let start_overlaps = x_start_self >= x_min && x_start_self <= x_max;
-        let end_overlaps = x_end_self >= x_min && x_end_self <= x_max;
+        let start_overlaps = self.start.x >= x_min && self.start.x <= x_max;
+        let end_overlaps = self.end.x >= x_min && self.end.x <= x_max;
 
         start_overlaps || end_overlaps
     }
 
     fn overlaps_vertically(&self, other: &CoordinateRange) -> bool {
-        let (start_self, end_self) = self.get_points();
-        let (start_other, end_other) = other.get_points();
-
-        let (_, y_start_self) = *start_self;
-        let (_, y_end_self) = *end_self;
-        let (_, y_start_other) = *start_other;
-        let (_, y_end_other) = *end_other;
-
-        let (y_min, y_max) = find_min_max(y_start_other, y_end_other);
+        let (y_min, y_max) = find_min_max(other.start.y, other.end.y);
 
-        let start_overlaps = y_start_self >= y_min && y_start_self <= y_max;
-        let end_overlaps = y_end_self >= y_min && y_end_self <= y_max;
+        let start_overlaps = self.start.y >= y_min && self.start.y <= y_max;
+        let end_overlaps = self.end.y >= y_min && self.end.y <= y_max;
 
         start_overlaps || end_overlaps
     }
@@ -79,52 +78,37 @@ impl CoordinateRange {
             || (other.overlaps_horizontally(self) && self.overlaps_vertically(other))
     }
 
-    pub fn contains_point(&self, other: &(usize, usize)) -> bool {
-        let (start, end) = self.get_points();
+    pub fn contains_point(&self, other: &Position2D) -> bool {
+        let (x_min, x_max) = find_min_max(self.start.x, self.end.x);
+        let (y_min, y_max) = find_min_max(self.start.y, self.end.y);
 
-        let (x, y) = *other;
-        let (x_start, y_start) = *start;
-        let (x_end, y_end) = *end;
-
-        let (x_min, x_max) = find_min_max(x_start, x_end);
-        let (y_min, y_max) = find_min_max(y_start, y_end);
-
-        let contained_x = x >= x_min && x <= x_max;
-        let contained_y = y >= y_min && y <= y_max;
+        let contained_x = other.x >= x_min && other.x <= x_max;
+        let contained_y = other.y >= y_min && other.y <= y_max;
 
         contained_x && contained_y
     }
 
-    pub fn get_points(&self) -> (&(usize, usize), &(usize, usize)) {
+    pub fn get_points(&self) -> (&Position2D, &Position2D) {
         let CoordinateRange { start, end } = self;
 
         (start, end)
     }
 
-    pub fn find_center(&self) -> (usize, usize) {
-        let (start, end) = self.get_points();
-
-        let (x_start, y_start) = *start;
-        let (x_end, y_end) = *end;
-
-        let (x_min, x_max) = find_min_max(x_start, x_end);
-        let (y_min, y_max) = find_min_max(y_start, y_end);
+    pub fn find_center(&self) -> Position2D {
+        let Vector2D { x, y } = self.start.offset_to(self.end);
 
-        ((x_max - x_min) / 2, (y_max - y_min) / 2)
+        Position2D::new(x.unsigned_abs() / 2, y.unsigned_abs() / 2)
     }
 
     pub fn direction(&self) -> Direction {
-        let CoordinateRange { start, end } = self;
-
-        let (x_start, y_start) = start;
-        let (x_end, y_end) = end;
+        let offset = self.start.offset_to(self.end);
 
-        match x_start == x_end {
-            true => match y_start > y_end {
+        match offset.x == 0 {
+            true => match offset.y < 0 {
                 true => Direction::Up,
                 _ => Direction::Down,
             },
-            _ => match x_start > x_end {
+            _ => match offset.x < 0 {
                 true => Direction::Left,
                 _ => Direction::Right,
             },
@@ -141,23 +125,15 @@ impl CoordinateRange {
             .fold((0, first_range), |current, (index, next_range)| {
                 let (_, current_range) = current;
 
-                let CoordinateRange { start, end } = current_range;
-                let (_, y_start_current) = start;
-                let (_, y_end_current) = end;
-
-                if y_start_current != y_end_current {
+                if current_range.start.y != current_range.end.y {
                     return (index + 1, next_range);
                 }
 
-                let CoordinateRange { start, end } = next_range;
-                let (_, y_start) = start;
-                let (_, y_end) = end;
-
-                if y_start != y_end {
+                if next_range.start.y != next_range.end.y {
                     return current;
                 }
 
-                match y_start > y_start_current {
+                match next_range.start.y > current_range.start.y {
                     true => current,
                     _ => (index + 1, next_range),
                 }
@@ -189,11 +165,11 @@ mod tests {
         let range_1 = CoordinateRange::new((7, 1), (7, 7));
         let range_2 = CoordinateRange::new((8, 6), (2, 6));
 
-        assert_eq!(range_1.intersects(&range_2), true);
+        assert!(range_1.intersects(&range_2));
 
         let range_1 = CoordinateRange::new((7, 1), (7, 7));
         let range_2 = CoordinateRange::new((8, 6), (2, 6));
 
-        assert_eq!(range_1.intersects(&range_2), true);
+        assert!(range_1.intersects(&range_2));
     }
 }
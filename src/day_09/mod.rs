@@ -4,36 +4,47 @@ mod pattern;
 mod rectangle;
 mod util;
 
-use crate::util::{Position2D, read_input};
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::{ParseError, Position2D},
+};
 use coordinate::coordinates_from_string;
 use pattern::TilePattern;
 use rectangle::Rectangle;
 use std::cmp::max;
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_09/input.txt");
+pub const INPUT: &str = include_str!("input.txt");
 
-    let coordinates = coordinates_from_string(&input);
-    largest_rectangle(&coordinates)
-}
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Model = Vec<Position2D>;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        coordinates_from_string(input)
+    }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_09/input.txt");
+    fn solve_part_1(coordinates: &Self::Model) -> Answer {
+        largest_rectangle(coordinates).into()
+    }
 
-    let coordinates = coordinates_from_string(&input);
-    largest_rectangle_colored_tiles(&coordinates)
+    fn solve_part_2(coordinates: &Self::Model) -> Answer {
+        largest_rectangle_colored_tiles(coordinates).into()
+    }
 }
 
 pub fn largest_rectangle(coordinates: &[Position2D]) -> usize {
     let mut largest = 0;
 
     for first in coordinates.iter() {
-        let (x1, y1) = first;
-
         for second in coordinates.iter() {
-            let (x2, y2) = second;
-
-            if x1 == x2 && y1 == y2 {
+            if first == second {
                 continue;
             }
 
@@ -52,13 +63,10 @@ pub fn largest_rectangle_colored_tiles(coordinates: &[Position2D]) -> usize {
     let mut largest = 0;
 
     for first in coordinates.iter() {
-        let (x1, y1) = first;
-
         for second in coordinates.iter() {
-            let (x2, y2) = second;
             let rectangle = Rectangle::from_corners(first, second);
 
-            if x1 == x2 && y1 == y2 || !pattern.includes_rectangle(&rectangle) {
+            if first == second || !pattern.includes_rectangle(&rectangle) {
                 continue;
             }
 
@@ -79,9 +87,9 @@ mod tests {
 
     #[test]
     fn finds_largest_possible_rectangle() {
-        let input = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
+        let input = include_str!("examples/example.txt");
 
-        let coordinates = coordinates_from_string(input);
+        let coordinates = coordinates_from_string(input).unwrap();
         let result = largest_rectangle(&coordinates);
 
         assert_eq!(result, 50);
@@ -89,9 +97,9 @@ mod tests {
 
     #[test]
     fn finds_largest_possible_rectangle_all_colors() {
-        let input = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
+        let input = include_str!("examples/example.txt");
 
-        let coordinates = coordinates_from_string(input);
+        let coordinates = coordinates_from_string(input).unwrap();
         let result = largest_rectangle_colored_tiles(&coordinates);
 
         assert_eq!(result, 24);
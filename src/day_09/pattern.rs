@@ -13,16 +13,16 @@ pub struct TilePattern {
 impl TilePattern {
     #[cfg(test)]
     pub fn from_string(input: &str) -> Self {
-        let coordinates = coordinates_from_string(input);
+        let coordinates = coordinates_from_string(input).unwrap();
         Self::from_coordinates(&coordinates)
     }
 
     fn construct_out_directions(
-        inner_ranges: &Vec<CoordinateRange>,
+        inner_ranges: &[CoordinateRange],
     ) -> HashMap<&CoordinateRange, Direction> {
         let mut out_directions: HashMap<&CoordinateRange, Direction> = HashMap::new();
 
-        let (mut index, top_range) = CoordinateRange::find_top(&inner_ranges);
+        let (mut index, top_range) = CoordinateRange::find_top(inner_ranges);
 
         out_directions.insert(top_range, Direction::Up);
 
@@ -30,14 +30,10 @@ impl TilePattern {
             let previous = inner_ranges.get(index).unwrap();
             let previous_out_direction = out_directions.get(previous).unwrap();
 
-            if index == 363 {}
-
             index = (index + 1) % inner_ranges.len();
             let next = inner_ranges.get(index).unwrap();
             let current_direction = next.direction();
 
-            if index == 364 {}
-
             let out_direction = match previous_out_direction {
                 Direction::Down => match current_direction {
                     Direction::Up => Direction::Left,
@@ -64,7 +60,7 @@ impl TilePattern {
     }
 
     fn construct_perimeter(
-        inner_ranges: &Vec<CoordinateRange>,
+        inner_ranges: &[CoordinateRange],
         out_directions: &HashMap<&CoordinateRange, Direction>,
     ) -> Vec<CoordinateRange> {
         inner_ranges
@@ -84,35 +80,26 @@ impl TilePattern {
                 let previous = inner_ranges.get(previous_index).unwrap();
                 let next = inner_ranges.get(next_index).unwrap();
 
-                let (start, end) = range.get_points();
-                let (mut x_start, mut y_start) = start.clone();
-                let (mut x_end, mut y_end) = end.clone();
-
                 let out_direction = out_directions.get(range).unwrap();
+                let shift = out_direction.vector();
 
-                match out_direction {
-                    Direction::Down => {
-                        y_end += 1;
-                        y_start += 1;
-                    }
-                    Direction::Up => {
-                        y_end -= 1;
-                        y_start -= 1;
-                    }
-                    Direction::Left => {
-                        x_end -= 1;
-                        x_start -= 1;
-                    }
-                    Direction::Right => {
-                        x_end += 1;
-                        x_start += 1;
-                    }
-                }
-
-                let start = (x_start, y_start);
-                let end = (x_end, y_end);
-
-                let (x_center, y_center) = range.find_center();
+                let (start, end) = range.get_points();
+                let start = start.checked_add(shift).unwrap();
+                let end = end.checked_add(shift).unwrap();
+
+                let Position2D {
+                    x: mut x_start,
+                    y: mut y_start,
+                } = start;
+                let Position2D {
+                    x: mut x_end,
+                    y: mut y_end,
+                } = end;
+
+                let Position2D {
+                    x: x_center,
+                    y: y_center,
+                } = range.find_center();
 
                 if next.contains_point(&start) || previous.contains_point(&start) {
                     match out_direction {
@@ -176,7 +163,7 @@ mod tests {
 
     #[test]
     fn determines_outward_directions() {
-        let coordinates = coordinates_from_string("1,1\n5,1\n5,5\n1,5");
+        let coordinates = coordinates_from_string("1,1\n5,1\n5,5\n1,5").unwrap();
         let ranges = CoordinateRange::many_from_coordinates(&coordinates);
         let directions = TilePattern::construct_out_directions(&ranges);
 
@@ -190,7 +177,7 @@ mod tests {
             ])
         );
 
-        let coordinates = coordinates_from_string("7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3");
+        let coordinates = coordinates_from_string(include_str!("examples/example.txt")).unwrap();
         let ranges = CoordinateRange::many_from_coordinates(&coordinates);
         let directions = TilePattern::construct_out_directions(&ranges);
 
@@ -211,7 +198,7 @@ mod tests {
 
     #[test]
     fn constructs_perimeter() {
-        let coordinates = coordinates_from_string("1,1\n5,1\n5,5\n1,5");
+        let coordinates = coordinates_from_string("1,1\n5,1\n5,5\n1,5").unwrap();
         let pattern = TilePattern::from_coordinates(&coordinates);
 
         // .O...O.
@@ -242,7 +229,7 @@ mod tests {
         // ........O#.#O.
         // .........O.O..
 
-        let input = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
+        let input = include_str!("examples/example.txt");
         let pattern = TilePattern::from_string(input);
 
         assert_eq!(
@@ -262,9 +249,9 @@ mod tests {
 
     #[test]
     fn pattern_includes_rectangle() {
-        let input = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3";
+        let input = include_str!("examples/example.txt");
         let pattern = TilePattern::from_string(input);
-        let rectangle = Rectangle::from_corners(&(9, 5), &(2, 3));
+        let rectangle = Rectangle::from_corners(&Position2D::new(9, 5), &Position2D::new(2, 3));
 
         // ..............
         // .......O...#..
@@ -276,9 +263,9 @@ mod tests {
         // .........#.O..
         // ..............
 
-        assert_eq!(pattern.includes_rectangle(&rectangle), true);
+        assert!(pattern.includes_rectangle(&rectangle));
 
-        let rectangle = Rectangle::from_corners(&(7, 1), &(11, 7));
+        let rectangle = Rectangle::from_corners(&Position2D::new(7, 1), &Position2D::new(11, 7));
 
         // ..............
         // .......#AAA#..
@@ -290,6 +277,6 @@ mod tests {
         // .......AA#A#..
         // ..............
 
-        assert_eq!(pattern.includes_rectangle(&rectangle), false);
+        assert!(!pattern.includes_rectangle(&rectangle));
     }
 }
@@ -1,5 +1,6 @@
 use super::coordinate_range::CoordinateRange;
 use super::util::find_min_max;
+use crate::util::{Arithmetic, Position2D};
 
 pub struct Rectangle {
     pub area: usize,
@@ -7,17 +8,14 @@ pub struct Rectangle {
 }
 
 impl Rectangle {
-    pub fn from_corners(start: &(usize, usize), end: &(usize, usize)) -> Self {
-        let (x_start, y_start) = *start;
-        let (x_end, y_end) = *end;
+    pub fn from_corners(start: &Position2D, end: &Position2D) -> Self {
+        let (x_min, x_max) = find_min_max(start.x, end.x);
+        let (y_min, y_max) = find_min_max(start.y, end.y);
 
-        let (x_min, x_max) = find_min_max(x_start, x_end);
-        let (y_min, y_max) = find_min_max(y_start, y_end);
+        let side_1 = (y_max - y_min).plus(1);
+        let side_2 = (x_max - x_min).plus(1);
 
-        let side_1 = y_max - y_min + 1;
-        let side_2 = x_max - x_min + 1;
-
-        let area = side_1 * side_2;
+        let area = side_1.times(side_2);
 
         Self {
             area,
@@ -0,0 +1,52 @@
+use std::{error::Error, fmt, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Unreadable {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Stdin(io::Error),
+    NotFound {
+        file_name: String,
+        searched: Vec<PathBuf>,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(f, "file {} does not exist", path.display()),
+            InputError::Unreadable { path, source } => {
+                write!(f, "could not read {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "could not read stdin: {source}"),
+            InputError::NotFound {
+                file_name,
+                searched,
+            } => {
+                let searched: Vec<String> = searched
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect();
+
+                write!(f, "no {file_name} found")?;
+
+                match searched.is_empty() {
+                    true => Ok(()),
+                    false => write!(f, " (searched {})", searched.join(", ")),
+                }
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Unreadable { source, .. } | InputError::Stdin(source) => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+mod error;
+mod resolver;
+
+pub use error::InputError;
+pub use resolver::InputResolver;
+
+use std::path::PathBuf;
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Default,
+    File(PathBuf),
+    Stdin,
+}
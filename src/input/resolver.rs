@@ -0,0 +1,205 @@
+use super::{InputError, InputSource};
+use std::{
+    env, fs,
+    io::{self, Read, stdin},
+    path::{Path, PathBuf},
+};
+
+const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+const CACHE_DIRECTORY: &str = "advent-of-code-2025";
+const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+pub struct InputResolver {
+    input_dir: Option<PathBuf>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl InputResolver {
+    pub fn new(input_dir: Option<PathBuf>, cache_dir: Option<PathBuf>) -> Self {
+        Self {
+            input_dir,
+            cache_dir,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let input_dir = env::var_os(INPUT_DIR_VARIABLE).map(PathBuf::from);
+
+        let cache_dir = env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .map(|cache| cache.join(CACHE_DIRECTORY));
+
+        Self::new(input_dir, cache_dir)
+    }
+}
+
+impl InputResolver {
+    pub fn resolve(
+        &self,
+        day: u8,
+        source: &InputSource,
+        embedded: &str,
+    ) -> Result<String, InputError> {
+        match source {
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => read_stdin(),
+            InputSource::Default => self.resolve_file(&input_file_name(day), embedded),
+        }
+    }
+
+    pub fn resolve_answers(
+        &self,
+        path: Option<&Path>,
+        embedded: &str,
+    ) -> Result<String, InputError> {
+        match path {
+            Some(path) => read_file(path),
+            None => self.resolve_file(ANSWERS_FILE_NAME, embedded),
+        }
+    }
+
+    fn resolve_file(&self, file_name: &str, embedded: &str) -> Result<String, InputError> {
+        if let Some(input_dir) = &self.input_dir {
+            return read_file(&input_dir.join(file_name));
+        }
+
+        let mut searched = vec![];
+
+        if let Some(cache_dir) = &self.cache_dir {
+            let path = cache_dir.join(file_name);
+
+            if path.is_file() {
+                return read_file(&path);
+            }
+
+            searched.push(path);
+        }
+
+        if !embedded.trim().is_empty() {
+            return Ok(embedded.to_string());
+        }
+
+        Err(InputError::NotFound {
+            file_name: file_name.to_string(),
+            searched,
+        })
+    }
+}
+
+fn input_file_name(day: u8) -> String {
+    format!("day_{day:02}.txt")
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+        _ => InputError::Unreadable {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+fn read_stdin() -> Result<String, InputError> {
+    let mut buffer = String::new();
+    stdin()
+        .read_to_string(&mut buffer)
+        .map_err(InputError::Stdin)?;
+
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temporary_directory(name: &str) -> PathBuf {
+        let directory = env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+
+        directory
+    }
+
+    #[test]
+    fn reads_explicit_file() {
+        let directory = temporary_directory("explicit");
+        let path = directory.join("custom.txt");
+        fs::write(&path, "L68\nR48").unwrap();
+
+        let resolver = InputResolver::new(None, None);
+        let result = resolver.resolve(1, &InputSource::File(path), "embedded");
+
+        assert_eq!(result.unwrap(), "L68\nR48");
+    }
+
+    #[test]
+    fn names_missing_explicit_file() {
+        let path = PathBuf::from("does/not/exist.txt");
+
+        let resolver = InputResolver::new(None, None);
+        let result = resolver.resolve(1, &InputSource::File(path), "embedded");
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "file does/not/exist.txt does not exist"
+        );
+    }
+
+    #[test]
+    fn prefers_input_directory() {
+        let input_dir = temporary_directory("input-dir");
+        fs::write(input_dir.join("day_03.txt"), "from input dir").unwrap();
+
+        let resolver = InputResolver::new(Some(input_dir.clone()), None);
+
+        let result = resolver.resolve(3, &InputSource::Default, "embedded");
+        assert_eq!(result.unwrap(), "from input dir");
+
+        let result = resolver.resolve(4, &InputSource::Default, "embedded");
+        assert!(
+            matches!(result, Err(InputError::Missing(path)) if path == input_dir.join("day_04.txt"))
+        );
+    }
+
+    #[test]
+    fn resolves_answers_next_to_inputs() {
+        let input_dir = temporary_directory("answers");
+        fs::write(input_dir.join("answers.toml"), "[day_01]").unwrap();
+
+        let resolver = InputResolver::new(Some(input_dir), None);
+        assert_eq!(
+            resolver.resolve_answers(None, "embedded").unwrap(),
+            "[day_01]"
+        );
+
+        let resolver = InputResolver::new(None, None);
+        assert_eq!(
+            resolver.resolve_answers(None, "embedded").unwrap(),
+            "embedded"
+        );
+    }
+
+    #[test]
+    fn falls_back_from_cache_to_embedded_input() {
+        let cache_dir = temporary_directory("cache");
+        fs::write(cache_dir.join("day_05.txt"), "from cache").unwrap();
+
+        let resolver = InputResolver::new(None, Some(cache_dir.clone()));
+
+        let result = resolver.resolve(5, &InputSource::Default, "embedded");
+        assert_eq!(result.unwrap(), "from cache");
+
+        let result = resolver.resolve(6, &InputSource::Default, "embedded");
+        assert_eq!(result.unwrap(), "embedded");
+
+        let result = resolver.resolve(6, &InputSource::Default, "");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "no day_06.txt found (searched {})",
+                cache_dir.join("day_06.txt").display()
+            )
+        );
+    }
+}
@@ -0,0 +1,17 @@
+mod bench;
+pub mod cli;
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod input;
+mod runner;
+mod scaffold;
+mod solution;
+pub mod util;
+mod verify;
@@ -0,0 +1,176 @@
+mod summary;
+
+pub use summary::render_summary;
+
+use crate::{
+    solution::{DayReport, Part, Registration},
+    util::catch_panic,
+};
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub title: &'static str,
+    pub result: Result<DayReport, String>,
+}
+
+impl DayRun {
+    pub fn is_success(&self) -> bool {
+        match &self.result {
+            Ok(report) => report.parts.iter().all(|part| part.answer.is_ok()),
+            Err(_) => false,
+        }
+    }
+}
+
+pub fn run_days(
+    registrations: &[&'static Registration],
+    parts: &[Part],
+    load: impl Fn(&Registration) -> Result<String, String> + Sync,
+    workers: usize,
+) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(registrations.len()));
+    let workers = workers.clamp(1, registrations.len().max(1));
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(registration) = registrations.get(index) else {
+                        break;
+                    };
+
+                    let run = DayRun {
+                        day: registration.day,
+                        title: registration.title,
+                        result: run_day(registration, parts, &load),
+                    };
+
+                    runs.lock().unwrap().push((index, run));
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|(index, _)| *index);
+
+    runs.into_iter().map(|(_, run)| run).collect()
+}
+
+fn run_day(
+    registration: &Registration,
+    parts: &[Part],
+    load: &impl Fn(&Registration) -> Result<String, String>,
+) -> Result<DayReport, String> {
+    let input = load(registration)?;
+
+    catch_panic(|| registration.solution.run(&input, parts))
+        .map_err(|message| format!("panicked: {message}"))?
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        solution::{Answer, PartReport, Puzzle},
+        util::ParseError,
+    };
+    use std::time::Duration;
+
+    struct Constant;
+
+    impl Puzzle for Constant {
+        fn run(&self, input: &str, parts: &[Part]) -> Result<DayReport, ParseError> {
+            let parts = parts
+                .iter()
+                .map(|part| PartReport {
+                    part: *part,
+                    answer: Ok(Answer::Text(input.to_string())),
+                    duration: Duration::ZERO,
+                })
+                .collect();
+
+            Ok(DayReport {
+                parse: Duration::ZERO,
+                parts,
+            })
+        }
+    }
+
+    struct Panicking;
+
+    impl Puzzle for Panicking {
+        fn run(&self, _: &str, _: &[Part]) -> Result<DayReport, ParseError> {
+            panic!("out of bounds");
+        }
+    }
+
+    static FIRST: Registration = Registration {
+        day: 1,
+        title: "First",
+        solution: &Constant,
+        input: "one",
+        examples: &[],
+    };
+
+    static SECOND: Registration = Registration {
+        day: 2,
+        title: "Second",
+        solution: &Panicking,
+        input: "two",
+        examples: &[],
+    };
+
+    static THIRD: Registration = Registration {
+        day: 3,
+        title: "Third",
+        solution: &Constant,
+        input: "",
+        examples: &[],
+    };
+
+    fn load(registration: &Registration) -> Result<String, String> {
+        match registration.input.is_empty() {
+            true => Err(String::from("no input")),
+            false => Ok(registration.input.to_string()),
+        }
+    }
+
+    #[test]
+    fn runs_days_in_parallel_and_keeps_order() {
+        let registrations = [&FIRST, &SECOND, &THIRD, &FIRST];
+        let runs = run_days(&registrations, &[Part::One], load, 3);
+
+        let days: Vec<u8> = runs.iter().map(|run| run.day).collect();
+        assert_eq!(days, vec![1, 2, 3, 1]);
+
+        let successes: Vec<bool> = runs.iter().map(DayRun::is_success).collect();
+        assert_eq!(successes, vec![true, false, false, true]);
+    }
+
+    #[test]
+    fn isolates_failures() {
+        let runs = run_days(&[&SECOND, &THIRD], &[Part::One], load, 1);
+
+        let errors: Vec<String> = runs
+            .into_iter()
+            .filter_map(|run| run.result.err())
+            .collect();
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("panicked: out of bounds at src/runner/mod.rs:"));
+        assert_eq!(errors[1], "no input");
+    }
+}
@@ -0,0 +1,111 @@
+use super::DayRun;
+use crate::solution::Part;
+
+pub fn render_summary(runs: &[DayRun], parts: &[Part]) -> String {
+    let mut header = vec![
+        String::from("day"),
+        String::from("title"),
+        String::from("parse"),
+    ];
+    for part in parts {
+        header.push(format!("part {}", part.number()));
+        header.push(String::from("time"));
+    }
+
+    let mut rows = vec![header];
+
+    for run in runs {
+        let mut row = vec![run.day.to_string(), run.title.to_string()];
+
+        match &run.result {
+            Ok(report) => {
+                row.push(format!("{:.2?}", report.parse));
+
+                for part in report.parts.iter() {
+                    match &part.answer {
+                        Ok(answer) => row.push(answer.to_string()),
+                        Err(message) => row.push(format!("panicked: {message}")),
+                    }
+                    row.push(format!("{:.2?}", part.duration));
+                }
+            }
+            Err(message) => row.push(format!("error: {message}")),
+        }
+
+        rows.push(row);
+    }
+
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .filter(|row| row.len() > column + 1)
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    rows.iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(column, cell)| match column + 1 == row.len() {
+                    true => cell.to_string(),
+                    false => format!("{cell:<0$}", widths[column]),
+                })
+                .collect();
+
+            cells.join("  ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::{Answer, DayReport, PartReport};
+    use std::time::Duration;
+
+    #[test]
+    fn renders_aligned_rows_in_day_order() {
+        let runs = vec![
+            DayRun {
+                day: 1,
+                title: "Secret Entrance",
+                result: Ok(DayReport {
+                    parse: Duration::from_micros(5),
+                    parts: vec![
+                        PartReport {
+                            part: Part::One,
+                            answer: Ok(Answer::from(1120u32)),
+                            duration: Duration::from_micros(12),
+                        },
+                        PartReport {
+                            part: Part::Two,
+                            answer: Err(String::from("overflow")),
+                            duration: Duration::from_micros(3),
+                        },
+                    ],
+                }),
+            },
+            DayRun {
+                day: 2,
+                title: "Gift Shop",
+                result: Err(String::from("line 1: expected a range like 11-22")),
+            },
+        ];
+
+        assert_eq!(
+            render_summary(&runs, &[Part::One, Part::Two]),
+            [
+                "day  title            parse   part 1  time     part 2              time",
+                "1    Secret Entrance  5.00µs  1120    12.00µs  panicked: overflow  3.00µs",
+                "2    Gift Shop        error: line 1: expected a range like 11-22",
+            ]
+            .join("\n")
+        );
+    }
+}
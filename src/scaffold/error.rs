@@ -0,0 +1,41 @@
+use std::{error::Error, fmt, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    NotARepository(PathBuf),
+    Exists(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+    Unregistrable { path: PathBuf, reason: &'static str },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::NotARepository(root) => write!(
+                f,
+                "{} is not the repository root (no src/lib.rs)",
+                root.display()
+            ),
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not write {}: {source}", path.display())
+            }
+            ScaffoldError::Unregistrable { path, reason } => {
+                write!(
+                    f,
+                    "could not register the day in {}: {reason}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,280 @@
+mod error;
+mod template;
+
+pub use error::ScaffoldError;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const LIB_FILE: &str = "src/lib.rs";
+const REGISTRY_FILE: &str = "src/solution/registry.rs";
+
+pub struct NewDay {
+    pub day: u8,
+    pub title: String,
+    pub model: String,
+}
+
+pub fn scaffold(root: &Path, new_day: &NewDay) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if !root.join(LIB_FILE).is_file() {
+        return Err(ScaffoldError::NotARepository(root.to_path_buf()));
+    }
+
+    let NewDay { day, title, model } = new_day;
+    let module = format!("day_{day:02}");
+    let directory = root.join("src").join(&module);
+
+    if directory.exists() {
+        return Err(ScaffoldError::Exists(directory));
+    }
+
+    let lib = read(&root.join(LIB_FILE))?;
+    let lib = insert_module(&lib, &module).ok_or(ScaffoldError::Unregistrable {
+        path: root.join(LIB_FILE),
+        reason: "no mod declarations found",
+    })?;
+
+    let registry = read(&root.join(REGISTRY_FILE))?;
+    let registry = register(
+        &registry,
+        *day,
+        &module,
+        &registration(*day, title, &module),
+    )
+    .map_err(|reason| ScaffoldError::Unregistrable {
+        path: root.join(REGISTRY_FILE),
+        reason,
+    })?;
+
+    let render = |template| template::render(template, *day, title, model);
+
+    let files = [
+        (directory.join("mod.rs"), render(template::MOD)),
+        (
+            directory.join(format!("{model}.rs")),
+            render(template::MODEL),
+        ),
+        (directory.join("input.txt"), String::new()),
+        (directory.join("readme.md"), render(template::README)),
+        (directory.join("examples/example.txt"), String::new()),
+        (
+            directory.join("examples/example.toml"),
+            render(template::EXAMPLE_ANSWERS),
+        ),
+        (root.join(LIB_FILE), lib),
+        (root.join(REGISTRY_FILE), registry),
+    ];
+
+    for (path, contents) in files.iter() {
+        write(path, contents)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn insert_module(lib: &str, module: &str) -> Option<String> {
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let declaration = format!("mod {module};");
+
+    let declarations: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("mod ") && line.ends_with(';'))
+        .map(|(index, _)| index)
+        .collect();
+
+    let last = *declarations.last()?;
+    let position = declarations
+        .into_iter()
+        .find(|index| lines[*index] > declaration.as_str())
+        .unwrap_or(last + 1);
+
+    lines.insert(position, &declaration);
+
+    Some(lines.join("\n") + "\n")
+}
+
+fn register(
+    registry: &str,
+    day: u8,
+    module: &str,
+    registration: &str,
+) -> Result<String, &'static str> {
+    let start = registry
+        .find("use crate::{")
+        .ok_or("no 'use crate::{..}' import found")?;
+    let end = start
+        + registry[start..]
+            .find("};")
+            .ok_or("unterminated 'use crate::{..}' import")?;
+
+    let mut modules: Vec<&str> = registry[start + "use crate::{".len()..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    if modules.contains(&module) {
+        return Err("the day is already registered");
+    }
+
+    modules.push(module);
+    modules.sort();
+
+    let registry = format!(
+        "{}use crate::{{{}}};{}",
+        &registry[..start],
+        modules.join(", "),
+        &registry[end + "};".len()..]
+    );
+
+    let table = registry
+        .find("pub const REGISTRY")
+        .ok_or("no REGISTRY table found")?;
+    let close = table
+        + registry[table..]
+            .find("\n];")
+            .ok_or("unterminated REGISTRY table")?;
+
+    let position = registry[table..close]
+        .match_indices("Registration::new(")
+        .map(|(index, call)| table + index + call.len())
+        .find(|arguments| registered_day(&registry[*arguments..]).is_some_and(|next| next > day))
+        .and_then(|arguments| registry[..arguments].rfind('\n'))
+        .unwrap_or(close);
+
+    Ok(format!(
+        "{}\n{registration}{}",
+        &registry[..position],
+        &registry[position..]
+    ))
+}
+
+fn registered_day(arguments: &str) -> Option<u8> {
+    let arguments = arguments.trim_start();
+    let digits = arguments
+        .find(|character: char| !character.is_ascii_digit())
+        .unwrap_or(arguments.len());
+
+    arguments[..digits].parse().ok()
+}
+
+fn registration(day: u8, title: &str, module: &str) -> String {
+    format!(
+        "    Registration::new({day}, {title:?}, &{module}::Day{day:02}, {module}::INPUT, {module}::EXAMPLES),"
+    )
+}
+
+// The edited files are emitted unformatted and left to rustfmt, which may not be installed.
+pub fn format(root: &Path) -> bool {
+    Command::new("rustfmt")
+        .args(["--edition", "2024", LIB_FILE, REGISTRY_FILE])
+        .current_dir(root)
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let io_error = |source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+
+    fs::write(path, contents).map_err(io_error)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    const LIB: &str = "pub mod cli;\nmod day_01;\nmod day_02;\nmod input;\npub mod util;\n";
+
+    const REGISTRY: &str = "use super::Puzzle;\nuse crate::{day_01, day_02};\n\npub const REGISTRY: &[Registration] = &[\n    Registration::new(1, \"One\", &day_01::Day01, day_01::INPUT, day_01::EXAMPLES),\n    Registration::new(\n        2,\n        \"Two\",\n        &day_02::Day02,\n        day_02::INPUT,\n        day_02::EXAMPLES,\n    ),\n];\n";
+
+    #[test]
+    fn inserts_module_in_order() {
+        assert_eq!(
+            insert_module(LIB, "day_03"),
+            Some(String::from(
+                "pub mod cli;\nmod day_01;\nmod day_02;\nmod day_03;\nmod input;\npub mod util;\n"
+            ))
+        );
+        assert_eq!(insert_module("fn main() {}", "day_03"), None);
+    }
+
+    #[test]
+    fn registers_day_in_order() {
+        let result = register(REGISTRY, 3, "day_03", "    Registration::new(3),").unwrap();
+
+        assert!(result.contains("use crate::{day_01, day_02, day_03};"));
+        assert!(
+            result.ends_with("        day_02::EXAMPLES,\n    ),\n    Registration::new(3),\n];\n")
+        );
+
+        let result = register(&result, 0, "day_00", "    Registration::new(0),").unwrap();
+
+        assert!(result.contains("use crate::{day_00, day_01, day_02, day_03};"));
+        assert!(result.contains("&[\n    Registration::new(0),\n    Registration::new(1, "));
+
+        let result = register(REGISTRY, 10, "day_10", "    Registration::new(10),").unwrap();
+        let result = register(&result, 9, "day_09", "    Registration::new(9),").unwrap();
+
+        assert!(result.contains("use crate::{day_01, day_02, day_09, day_10};"));
+        assert!(
+            result.ends_with("    ),\n    Registration::new(9),\n    Registration::new(10),\n];\n")
+        );
+
+        assert_eq!(
+            register(REGISTRY, 2, "day_02", "    Registration::new(2),"),
+            Err("the day is already registered")
+        );
+    }
+
+    #[test]
+    fn scaffolds_day_module() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/solution")).unwrap();
+        fs::write(root.join(LIB_FILE), LIB).unwrap();
+        fs::write(root.join(REGISTRY_FILE), REGISTRY).unwrap();
+
+        let new_day = NewDay {
+            day: 3,
+            title: String::from("Lobby"),
+            model: String::from("battery_bank"),
+        };
+
+        let files = scaffold(&root, &new_day).unwrap();
+        assert_eq!(files.len(), 8);
+
+        let module = fs::read_to_string(root.join("src/day_03/mod.rs")).unwrap();
+        assert!(module.contains("use battery_bank::BatteryBank;"));
+        assert!(module.contains("impl Solution for Day03 {"));
+
+        let registry = fs::read_to_string(root.join(REGISTRY_FILE)).unwrap();
+        assert!(registry.contains(
+            "Registration::new(3, \"Lobby\", &day_03::Day03, day_03::INPUT, day_03::EXAMPLES),"
+        ));
+
+        assert!(root.join("src/day_03/battery_bank.rs").is_file());
+        assert!(matches!(
+            scaffold(&root, &new_day),
+            Err(ScaffoldError::Exists(_))
+        ));
+    }
+}
@@ -0,0 +1,111 @@
+pub const MOD: &str = r#"mod {model};
+
+use crate::{
+    solution::{Answer, Example, Solution},
+    util::ParseError,
+};
+use {model}::{Model};
+
+pub const INPUT: &str = include_str!("input.txt");
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "example",
+    include_str!("examples/example.txt"),
+    include_str!("examples/example.toml"),
+)];
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Model = {Model};
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError> {
+        {Model}::from_string(input)
+    }
+
+    fn solve_part_1(model: &Self::Model) -> Answer {
+        model.lines.len().into()
+    }
+
+    fn solve_part_2(model: &Self::Model) -> Answer {
+        model.lines.len().into()
+    }
+}
+"#;
+
+pub const MODEL: &str = r#"use crate::util::{ParseError, parse::lines};
+
+#[derive(Debug, PartialEq)]
+pub struct {Model} {
+    pub lines: Vec<String>,
+}
+
+impl {Model} {
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
+        let lines = lines(|line| Ok(line.to_string()))(input)?;
+
+        Ok(Self { lines })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_lines() {
+        let result = {Model}::from_string("first\nsecond").unwrap();
+
+        assert_eq!(result.lines, vec!["first", "second"]);
+    }
+}
+"#;
+
+pub const README: &str = "--- Day {number}: {title} ---
+";
+
+pub const EXAMPLE_ANSWERS: &str = "[day_{day}]
+# part_1 =
+# part_2 =
+";
+
+pub fn render(template: &str, day: u8, title: &str, model: &str) -> String {
+    template
+        .replace("{day}", &format!("{day:02}"))
+        .replace("{number}", &day.to_string())
+        .replace("{title}", title)
+        .replace("{model}", model)
+        .replace("{Model}", &type_name(model))
+}
+
+fn type_name(model: &str) -> String {
+    model
+        .split('_')
+        .map(|word| {
+            let mut characters = word.chars();
+
+            match characters.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + characters.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_placeholders() {
+        assert_eq!(
+            render(
+                "mod {model}; struct Day{day}({Model}); // {number}: {title}",
+                3,
+                "Lobby",
+                "battery_bank"
+            ),
+            "mod battery_bank; struct Day03(BatteryBank); // 3: Lobby"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::util::BigUint;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Answer {
+    Number(u128),
+    Big(BigUint),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Big(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value.into())
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as u128)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        match u128::try_from(value) {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        match value.to_u128() {
+            Some(number) => Answer::Number(number),
+            None => Answer::Big(value),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn displays_answers() {
+        assert_eq!(Answer::from(42_u32).to_string(), "42");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(String::from("abc")).to_string(), "abc");
+        assert_eq!(Answer::from(25272_i64), Answer::Number(25272));
+        assert_eq!(Answer::from(-3_i64).to_string(), "-3");
+        assert_eq!(Answer::from(BigUint::from(7_u8)), Answer::Number(7));
+
+        let big = &BigUint::from(u128::MAX) * &BigUint::from(10_u8);
+        assert_eq!(Answer::from(big.clone()), Answer::Big(big));
+    }
+}
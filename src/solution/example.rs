@@ -0,0 +1,15 @@
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub answers: &'static str,
+}
+
+impl Example {
+    pub const fn new(name: &'static str, input: &'static str, answers: &'static str) -> Self {
+        Self {
+            name,
+            input,
+            answers,
+        }
+    }
+}
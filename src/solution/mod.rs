@@ -0,0 +1,146 @@
+mod answer;
+mod example;
+mod registry;
+mod report;
+
+pub use answer::Answer;
+pub use example::Example;
+pub use registry::{REGISTRY, Registration, find};
+pub use report::{DayReport, PartReport};
+
+use crate::util::{ParseError, catch_panic};
+use std::time::Instant;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub trait Solution {
+    type Model;
+
+    fn parse(input: &str) -> Result<Self::Model, ParseError>;
+
+    fn solve_part_1(model: &Self::Model) -> Answer;
+
+    fn solve_part_2(model: &Self::Model) -> Answer;
+}
+
+pub trait Puzzle: Sync {
+    fn run(&self, input: &str, parts: &[Part]) -> Result<DayReport, ParseError>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn run(&self, input: &str, parts: &[Part]) -> Result<DayReport, ParseError> {
+        let start = Instant::now();
+        let model = S::parse(input)?;
+        let parse = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = catch_panic(|| match part {
+                    Part::One => S::solve_part_1(&model),
+                    Part::Two => S::solve_part_2(&model),
+                });
+
+                PartReport {
+                    part: *part,
+                    answer,
+                    duration: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(DayReport { parse, parts })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::parse_lines;
+    use std::cell::Cell;
+
+    thread_local! {
+        static PARSED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct Counting;
+
+    impl Solution for Counting {
+        type Model = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Model, ParseError> {
+            PARSED.set(PARSED.get() + 1);
+            parse_lines(input, |line| {
+                line.parse()
+                    .map_err(|_| ParseError::new("expected a number"))
+            })
+        }
+
+        fn solve_part_1(model: &Self::Model) -> Answer {
+            model.iter().sum::<u32>().into()
+        }
+
+        fn solve_part_2(model: &Self::Model) -> Answer {
+            if model.is_empty() {
+                panic!("nothing to multiply");
+            }
+
+            model.iter().product::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn parses_input_once_for_both_parts() {
+        let report = Counting.run("2\n3\n4", &[Part::One, Part::Two]).unwrap();
+
+        let answers: Vec<(Part, Result<Answer, String>)> = report
+            .parts
+            .into_iter()
+            .map(|report| (report.part, report.answer))
+            .collect();
+
+        assert_eq!(PARSED.get(), 1);
+        assert_eq!(
+            answers,
+            vec![
+                (Part::One, Ok(Answer::from(9_u32))),
+                (Part::Two, Ok(Answer::from(24_u32)))
+            ]
+        );
+    }
+
+    #[test]
+    fn isolates_panicking_parts() {
+        let report = Counting.run("", &[Part::One, Part::Two]).unwrap();
+
+        let mut answers = report.parts.into_iter().map(|report| report.answer);
+
+        assert_eq!(answers.next(), Some(Ok(Answer::from(0_u32))));
+        assert!(
+            answers.next().and_then(Result::err).is_some_and(
+                |error| error.starts_with("nothing to multiply at src/solution/mod.rs:")
+            )
+        );
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let error = Counting.run("1\nx", &[Part::One]).unwrap_err();
+
+        assert_eq!(error.to_string(), "line 2: expected a number");
+    }
+}
@@ -0,0 +1,112 @@
+use super::{Example, Puzzle};
+use crate::{day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09};
+
+pub struct Registration {
+    pub day: u8,
+    pub title: &'static str,
+    pub solution: &'static dyn Puzzle,
+    pub input: &'static str,
+    pub examples: &'static [Example],
+}
+
+impl Registration {
+    const fn new(
+        day: u8,
+        title: &'static str,
+        solution: &'static dyn Puzzle,
+        input: &'static str,
+        examples: &'static [Example],
+    ) -> Self {
+        Self {
+            day,
+            title,
+            solution,
+            input,
+            examples,
+        }
+    }
+}
+
+pub const REGISTRY: &[Registration] = &[
+    Registration::new(
+        1,
+        "Secret Entrance",
+        &day_01::Day01,
+        day_01::INPUT,
+        day_01::EXAMPLES,
+    ),
+    Registration::new(
+        2,
+        "Gift Shop",
+        &day_02::Day02,
+        day_02::INPUT,
+        day_02::EXAMPLES,
+    ),
+    Registration::new(3, "Lobby", &day_03::Day03, day_03::INPUT, day_03::EXAMPLES),
+    Registration::new(
+        4,
+        "Printing Department",
+        &day_04::Day04,
+        day_04::INPUT,
+        day_04::EXAMPLES,
+    ),
+    Registration::new(
+        5,
+        "Cafeteria",
+        &day_05::Day05,
+        day_05::INPUT,
+        day_05::EXAMPLES,
+    ),
+    Registration::new(
+        6,
+        "Trash Compactor",
+        &day_06::Day06,
+        day_06::INPUT,
+        day_06::EXAMPLES,
+    ),
+    Registration::new(
+        7,
+        "Laboratories",
+        &day_07::Day07,
+        day_07::INPUT,
+        day_07::EXAMPLES,
+    ),
+    Registration::new(
+        8,
+        "Playground",
+        &day_08::Day08,
+        day_08::INPUT,
+        day_08::EXAMPLES,
+    ),
+    Registration::new(
+        9,
+        "Movie Theater",
+        &day_09::Day09,
+        day_09::INPUT,
+        day_09::EXAMPLES,
+    ),
+];
+
+pub fn find(day: u8) -> Option<&'static Registration> {
+    REGISTRY.iter().find(|registration| registration.day == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registers_days_in_order() {
+        assert!(REGISTRY.windows(2).all(|pair| pair[0].day < pair[1].day));
+    }
+
+    #[test]
+    fn finds_registered_day() {
+        assert_eq!(
+            find(7).map(|registration| registration.title),
+            Some("Laboratories")
+        );
+        assert!(find(0).is_none());
+        assert!(find(26).is_none());
+    }
+}
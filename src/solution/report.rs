@@ -0,0 +1,15 @@
+use super::{Answer, Part};
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer, String>,
+    pub duration: Duration,
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
@@ -0,0 +1,150 @@
+use std::{
+    any::type_name,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static CHECKED: AtomicBool = AtomicBool::new(cfg!(feature = "checked"));
+
+pub fn set_checked(checked: bool) {
+    CHECKED.store(checked, Ordering::Relaxed);
+}
+
+pub fn is_checked() -> bool {
+    #[cfg(test)]
+    if let Some(checked) = CHECKED_OVERRIDE.get() {
+        return checked;
+    }
+
+    CHECKED.load(Ordering::Relaxed)
+}
+
+// Tests run in parallel, so they switch checked mode for their own thread only.
+#[cfg(test)]
+thread_local! {
+    static CHECKED_OVERRIDE: std::cell::Cell<Option<bool>> = const { std::cell::Cell::new(None) };
+}
+
+#[cfg(test)]
+pub fn with_checked<T>(checked: bool, f: impl FnOnce() -> T) -> T {
+    let previous = CHECKED_OVERRIDE.replace(Some(checked));
+    let result = f();
+    CHECKED_OVERRIDE.set(previous);
+    result
+}
+
+// Outside checked mode these are the plain operators: they panic on overflow in debug builds and
+// wrap in release builds.
+pub trait Arithmetic: Copy + fmt::Display {
+    fn plus(self, other: Self) -> Self;
+
+    fn minus(self, other: Self) -> Self;
+
+    fn times(self, other: Self) -> Self;
+}
+
+macro_rules! impl_arithmetic {
+    ($($type:ty),*) => {$(
+        impl Arithmetic for $type {
+            #[track_caller]
+            fn plus(self, other: Self) -> Self {
+                if !is_checked() {
+                    return self + other;
+                }
+
+                match self.checked_add(other) {
+                    Some(result) => result,
+                    None => overflow(self, '+', other),
+                }
+            }
+
+            #[track_caller]
+            fn minus(self, other: Self) -> Self {
+                if !is_checked() {
+                    return self - other;
+                }
+
+                match self.checked_sub(other) {
+                    Some(result) => result,
+                    None => overflow(self, '-', other),
+                }
+            }
+
+            #[track_caller]
+            fn times(self, other: Self) -> Self {
+                if !is_checked() {
+                    return self * other;
+                }
+
+                match self.checked_mul(other) {
+                    Some(result) => result,
+                    None => overflow(self, '*', other),
+                }
+            }
+        }
+    )*};
+}
+
+impl_arithmetic!(i32, i64, u32, u64, u128, usize);
+
+#[track_caller]
+fn overflow<T: Arithmetic>(left: T, operator: char, right: T) -> T {
+    panic!("{} overflow in {left} {operator} {right}", type_name::<T>())
+}
+
+#[track_caller]
+pub fn sum<T: Arithmetic + From<u8>>(values: impl IntoIterator<Item = T>) -> T {
+    let mut total = T::from(0);
+
+    for value in values {
+        total = total.plus(value);
+    }
+
+    total
+}
+
+#[track_caller]
+pub fn product<T: Arithmetic + From<u8>>(values: impl IntoIterator<Item = T>) -> T {
+    let mut total = T::from(1);
+
+    for value in values {
+        total = total.times(value);
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::catch_panic;
+
+    #[test]
+    fn accumulates_without_overflow() {
+        assert_eq!(sum([1_u64, 2, 3]), 6);
+        assert_eq!(product([2_usize, 3, 4]), 24);
+        assert_eq!(7_i32.minus(9), -2);
+        assert_eq!(sum(Vec::<u32>::new()), 0);
+    }
+
+    #[test]
+    fn falls_back_to_plain_operators_outside_checked_mode() {
+        let result = with_checked(false, || catch_panic(|| u64::MAX.plus(1)));
+
+        match cfg!(debug_assertions) {
+            true => assert!(
+                result
+                    .unwrap_err()
+                    .starts_with("attempt to add with overflow at src/util/arithmetic.rs:")
+            ),
+            false => assert_eq!(result.ok(), Some(0)),
+        }
+    }
+
+    #[test]
+    fn reports_overflow_location_in_checked_mode() {
+        let message = with_checked(true, || catch_panic(|| u32::MAX.times(2))).unwrap_err();
+
+        assert!(message.starts_with("u32 overflow in 4294967295 * 2 at src/util/arithmetic.rs:"));
+    }
+}
@@ -0,0 +1,317 @@
+use super::ParseError;
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul},
+    str::FromStr,
+};
+
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+
+        Self { limbs }
+    }
+}
+
+impl BigUint {
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.limbs.len() <= 4 {
+            true => Some(
+                self.limbs
+                    .iter()
+                    .rev()
+                    .fold(0, |result, limb| (result << 32) | u128::from(*limb)),
+            ),
+            false => None,
+        }
+    }
+
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0_u64;
+
+        for limb in self.limbs.iter_mut() {
+            let product = u64::from(*limb) * u64::from(factor) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    fn add_small(&mut self, value: u32) {
+        let mut carry = u64::from(value);
+
+        for limb in self.limbs.iter_mut() {
+            if carry == 0 {
+                return;
+            }
+
+            let sum = u64::from(*limb) + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    fn div_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0_u64;
+
+        for limb in self.limbs.iter_mut().rev() {
+            let value = (remainder << 32) | u64::from(*limb);
+            *limb = (value / u64::from(divisor)) as u32;
+            remainder = value % u64::from(divisor);
+        }
+
+        *self = Self::from_limbs(std::mem::take(&mut self.limbs));
+
+        remainder as u32
+    }
+}
+
+impl From<u8> for BigUint {
+    fn from(value: u8) -> Self {
+        u128::from(value).into()
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        u128::from(value).into()
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(value: u128) -> Self {
+        Self::from_limbs((0..4).map(|index| (value >> (32 * index)) as u32).collect())
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if let Some(index) = input.find(|character: char| !character.is_ascii_digit()) {
+            return Err(ParseError::new("expected a digit").at_column(index + 1));
+        }
+
+        if input.is_empty() {
+            return Err(ParseError::new("expected a number"));
+        }
+
+        let mut result = Self::zero();
+
+        for chunk in input.as_bytes().chunks(DECIMAL_CHUNK_DIGITS) {
+            let value = chunk
+                .iter()
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+
+            result.mul_small(10_u32.pow(chunk.len() as u32));
+            result.add_small(value);
+        }
+
+        Ok(result)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut remaining = self.clone();
+        let mut chunks = vec![];
+
+        while !remaining.is_zero() {
+            chunks.push(remaining.div_small(DECIMAL_CHUNK));
+        }
+
+        let Some(first) = chunks.pop() else {
+            return write!(f, "0");
+        };
+
+        write!(f, "{first}")?;
+
+        for chunk in chunks.iter().rev() {
+            write!(f, "{chunk:0width$}", width = DECIMAL_CHUNK_DIGITS)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, other: &BigUint) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+
+        let mut carry = 0_u64;
+
+        for (index, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = u64::from(*limb) + u64::from(*other.limbs.get(index).unwrap_or(&0)) + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+        }
+
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+}
+
+impl Add<&BigUint> for BigUint {
+    type Output = BigUint;
+
+    fn add(mut self, other: &BigUint) -> BigUint {
+        self += other;
+        self
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: BigUint) -> BigUint {
+        self + &other
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        let mut limbs = vec![0_u32; self.limbs.len() + other.limbs.len()];
+
+        for (index, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0_u64;
+
+            for (other_index, b) in other.limbs.iter().enumerate() {
+                let slot = &mut limbs[index + other_index];
+                let product = u64::from(*a) * u64::from(*b) + u64::from(*slot) + carry;
+                *slot = product as u32;
+                carry = product >> 32;
+            }
+
+            limbs[index + other.limbs.len()] = carry as u32;
+        }
+
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: BigUint) -> BigUint {
+        &self * &other
+    }
+}
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, next| total + next)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_and_formats_decimals() {
+        let digits = "123456789012345678901234567890123456789012345678901234567890";
+        let number: BigUint = digits.parse().unwrap();
+
+        assert_eq!(number.to_string(), digits);
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!("000".parse::<BigUint>(), Ok(BigUint::zero()));
+        assert_eq!(
+            "1000000000".parse::<BigUint>(),
+            Ok(BigUint::from(1_000_000_000_u64))
+        );
+        assert_eq!(
+            "12x"
+                .parse::<BigUint>()
+                .err()
+                .map(|error| error.to_string()),
+            Some(String::from("column 3: expected a digit"))
+        );
+    }
+
+    #[test]
+    fn converts_machine_integers() {
+        assert_eq!(BigUint::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(BigUint::from(u128::MAX).to_u128(), Some(u128::MAX));
+        assert_eq!(
+            (BigUint::from(u128::MAX) + BigUint::from(1_u8)).to_u128(),
+            None
+        );
+    }
+
+    #[test]
+    fn adds_and_multiplies() {
+        let max = BigUint::from(u128::MAX);
+
+        assert_eq!(
+            (max.clone() + BigUint::from(1_u8)).to_string(),
+            "340282366920938463463374607431768211456"
+        );
+        assert_eq!(
+            (&max * &max).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert_eq!(
+            (&BigUint::from(12_345_u64) * &BigUint::from(6_789_u64)).to_u128(),
+            Some(83_810_205)
+        );
+        assert!((&max * &BigUint::zero()).is_zero());
+    }
+
+    #[test]
+    fn compares_by_magnitude() {
+        let small = BigUint::from(u64::MAX);
+        let large = &small * &small;
+
+        assert!(small < large);
+        assert!(BigUint::zero() < small);
+        assert_eq!(large.cmp(&large.clone()), Ordering::Equal);
+        assert!(BigUint::from(2_u8 << 4) > BigUint::from(31_u8));
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+}
+
+impl DisjointSet {
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+
+        if a == b {
+            return false;
+        }
+
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut indices: HashMap<usize, usize> = HashMap::new();
+        let mut components: Vec<Vec<usize>> = vec![];
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = *indices.entry(root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+
+            components[index].push(element);
+        }
+
+        components
+    }
+
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|element| self.parents[*element] == *element)
+            .map(|root| self.sizes[root])
+            .collect();
+
+        sizes.sort_by(|a, b| b.cmp(a));
+
+        sizes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn merges_components_by_size() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(set.component_count(), 6);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+
+        assert!(set.connected(0, 3));
+        assert!(!set.connected(0, 4));
+        assert_eq!(set.component_size(2), 4);
+        assert_eq!(set.component_size(5), 1);
+        assert_eq!(set.component_count(), 3);
+        assert_eq!(set.component_sizes(), vec![4, 1, 1]);
+    }
+
+    #[test]
+    fn lists_components_in_order() {
+        let mut set = DisjointSet::new(5);
+        set.union(4, 1);
+        set.union(3, 0);
+
+        assert_eq!(set.components(), vec![vec![0, 3], vec![1, 4], vec![2]]);
+        assert_eq!(set.len(), 5);
+        assert!(DisjointSet::new(0).is_empty());
+    }
+
+    #[test]
+    fn compresses_long_chains() {
+        let mut set = DisjointSet::new(1000);
+
+        for element in 1..1000 {
+            set.union(element - 1, element);
+        }
+
+        assert_eq!(set.component_count(), 1);
+        assert_eq!(set.component_size(0), 1000);
+
+        let root = set.find(999);
+        assert_eq!(set.parents[999], root);
+        assert_eq!(set.parents[0], root);
+    }
+}
@@ -0,0 +1,402 @@
+use super::{Grid, Position2D};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque, hash_map::Entry},
+    hash::Hash,
+    ops::Add,
+};
+
+pub trait Graph<N> {
+    fn neighbors(&self, node: N) -> impl Iterator<Item = N>;
+}
+
+pub trait WeightedGraph<N> {
+    fn edges(&self, node: N) -> impl Iterator<Item = (N, u64)>;
+}
+
+impl<N, I, F> Graph<N> for F
+where
+    F: Fn(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    fn neighbors(&self, node: N) -> impl Iterator<Item = N> {
+        self(node).into_iter()
+    }
+}
+
+impl<T> Graph<Position2D> for Grid<T> {
+    fn neighbors(&self, node: Position2D) -> impl Iterator<Item = Position2D> {
+        Grid::neighbors(self, node)
+    }
+}
+
+pub struct Unweighted<G>(pub G);
+
+impl<N, G: Graph<N>> WeightedGraph<N> for Unweighted<G> {
+    fn edges(&self, node: N) -> impl Iterator<Item = (N, u64)> {
+        self.0.neighbors(node).map(|neighbor| (neighbor, 1))
+    }
+}
+
+pub fn bfs<N: Copy + Eq + Hash>(
+    graph: &impl Graph<N>,
+    starts: impl IntoIterator<Item = N>,
+) -> HashMap<N, usize> {
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if distances.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+
+        for neighbor in graph.neighbors(node) {
+            if let Entry::Vacant(entry) = distances.entry(neighbor) {
+                entry.insert(distance + 1);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    distances
+}
+
+pub fn dijkstra<N: Copy + Ord + Hash>(graph: &impl WeightedGraph<N>, start: N) -> HashMap<N, u64> {
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((cost, node))) = queue.pop() {
+        if distances.get(&node).is_some_and(|best| cost > *best) {
+            continue;
+        }
+
+        for (neighbor, weight) in graph.edges(node) {
+            let next = cost + weight;
+
+            if distances.get(&neighbor).is_none_or(|best| next < *best) {
+                distances.insert(neighbor, next);
+                queue.push(Reverse((next, neighbor)));
+            }
+        }
+    }
+
+    distances
+}
+
+pub fn a_star<N: Copy + Ord + Hash>(
+    graph: &impl WeightedGraph<N>,
+    start: N,
+    goal: N,
+    heuristic: impl Fn(N) -> u64,
+) -> Option<(u64, Vec<N>)> {
+    let mut costs = HashMap::from([(start, 0)]);
+    let mut previous: HashMap<N, N> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if node == goal {
+            let mut path = vec![goal];
+
+            while let Some(before) = previous.get(path.last()?) {
+                path.push(*before);
+            }
+
+            path.reverse();
+
+            return Some((cost, path));
+        }
+
+        if costs.get(&node).is_some_and(|best| cost > *best) {
+            continue;
+        }
+
+        for (neighbor, weight) in graph.edges(node) {
+            let next = cost + weight;
+
+            if costs.get(&neighbor).is_none_or(|best| next < *best) {
+                costs.insert(neighbor, next);
+                previous.insert(neighbor, node);
+                queue.push(Reverse((next + heuristic(neighbor), next, neighbor)));
+            }
+        }
+    }
+
+    None
+}
+
+pub fn connected_components<N: Copy + Eq + Hash>(
+    graph: &impl Graph<N>,
+    nodes: impl IntoIterator<Item = N>,
+) -> Vec<Vec<N>> {
+    let mut visited = HashSet::new();
+    let mut components = vec![];
+
+    for node in nodes {
+        if !visited.insert(node) {
+            continue;
+        }
+
+        let mut component = vec![node];
+        let mut stack = vec![node];
+
+        while let Some(current) = stack.pop() {
+            for neighbor in graph.neighbors(current) {
+                if visited.insert(neighbor) {
+                    component.push(neighbor);
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        components.push(component);
+    }
+
+    components
+}
+
+pub fn topological_sort<N: Copy + Eq + Hash>(
+    graph: &impl Graph<N>,
+    nodes: impl IntoIterator<Item = N>,
+) -> Option<Vec<N>> {
+    let nodes: Vec<N> = nodes.into_iter().collect();
+    let mut incoming: HashMap<N, usize> = nodes.iter().map(|node| (*node, 0)).collect();
+
+    for node in nodes.iter() {
+        for neighbor in graph.neighbors(*node) {
+            *incoming.entry(neighbor).or_insert(0) += 1;
+        }
+    }
+
+    let mut queue: VecDeque<N> = nodes
+        .iter()
+        .filter(|node| incoming[*node] == 0)
+        .copied()
+        .collect();
+    let mut order = vec![];
+
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+
+        for neighbor in graph.neighbors(node) {
+            let count = incoming.get_mut(&neighbor)?;
+            *count -= 1;
+
+            if *count == 0 {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    match order.len() == incoming.len() {
+        true => Some(order),
+        false => None,
+    }
+}
+
+pub fn count_paths<N, C>(graph: &impl Graph<N>, source: N, is_sink: impl Fn(N) -> bool) -> Option<C>
+where
+    N: Copy + Eq + Hash,
+    C: Clone + From<u8> + for<'a> Add<&'a C, Output = C>,
+{
+    let mut counts: HashMap<N, C> = HashMap::new();
+    let mut visiting = HashSet::new();
+    let mut stack = vec![(source, false)];
+
+    while let Some((node, expanded)) = stack.pop() {
+        if counts.contains_key(&node) {
+            continue;
+        }
+
+        if is_sink(node) {
+            counts.insert(node, C::from(1));
+            continue;
+        }
+
+        match expanded {
+            true => {
+                let total = graph
+                    .neighbors(node)
+                    .try_fold(C::from(0), |total, neighbor| {
+                        Some(total + counts.get(&neighbor)?)
+                    })?;
+
+                visiting.remove(&node);
+                counts.insert(node, total);
+            }
+            false => {
+                if !visiting.insert(node) {
+                    return None;
+                }
+
+                stack.push((node, true));
+                stack.extend(
+                    graph
+                        .neighbors(node)
+                        .filter(|neighbor| !counts.contains_key(neighbor))
+                        .map(|neighbor| (neighbor, false)),
+                );
+            }
+        }
+    }
+
+    counts.remove(&source)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::BigUint;
+
+    const MAZE: &str = "....#\n.##.#\n.#...\n.#.#.\n...#.";
+
+    fn maze() -> Grid<bool> {
+        Grid::from_string(MAZE, |character| match character {
+            '.' => Some(true),
+            '#' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn searches_breadth_first() {
+        let maze = maze();
+        let open = |position: Position2D| {
+            maze.neighbors(position)
+                .filter(|neighbor| maze.get(*neighbor) == Some(&true))
+                .collect::<Vec<_>>()
+        };
+
+        let distances = bfs(&open, [Position2D::new(0, 0)]);
+
+        assert_eq!(distances[&Position2D::new(4, 4)], 8);
+        assert_eq!(distances[&Position2D::new(2, 3)], 7);
+        assert!(!distances.contains_key(&Position2D::new(4, 0)));
+
+        let distances = bfs(&maze, [Position2D::new(0, 0)]);
+        assert_eq!(distances[&Position2D::new(2, 3)], 5);
+        assert_eq!(distances.len(), 25);
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        let edges = |node: char| match node {
+            'a' => vec![('b', 7), ('c', 2)],
+            'c' => vec![('b', 3), ('d', 8)],
+            'b' => vec![('d', 1)],
+            _ => vec![],
+        };
+
+        struct Edges<F>(F);
+
+        impl<F: Fn(char) -> Vec<(char, u64)>> WeightedGraph<char> for Edges<F> {
+            fn edges(&self, node: char) -> impl Iterator<Item = (char, u64)> {
+                (self.0)(node).into_iter()
+            }
+        }
+
+        let distances = dijkstra(&Edges(edges), 'a');
+        assert_eq!(distances[&'b'], 5);
+        assert_eq!(distances[&'d'], 6);
+
+        let result = a_star(&Edges(edges), 'a', 'd', |_| 0);
+        assert_eq!(result, Some((6, vec!['a', 'c', 'b', 'd'])));
+        assert_eq!(a_star(&Edges(edges), 'd', 'a', |_| 0), None);
+    }
+
+    #[test]
+    fn guides_a_star_through_grid() {
+        let maze = maze();
+        let open = |position: Position2D| {
+            maze.neighbors(position)
+                .filter(|neighbor| maze.get(*neighbor) == Some(&true))
+                .collect::<Vec<_>>()
+        };
+
+        let goal = Position2D::new(4, 4);
+        let result = a_star(&Unweighted(open), Position2D::new(0, 0), goal, |position| {
+            position.manhattan_distance(goal) as u64
+        });
+
+        let (cost, path) = result.unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert!(
+            path.windows(2)
+                .all(|step| step[0].manhattan_distance(step[1]) == 1)
+        );
+    }
+
+    #[test]
+    fn groups_connected_components() {
+        let edges = |node: u32| match node {
+            1 => vec![2],
+            2 => vec![1, 3],
+            3 => vec![2],
+            4 => vec![5],
+            5 => vec![4],
+            _ => vec![],
+        };
+
+        assert_eq!(
+            connected_components(&edges, 1..=6),
+            vec![vec![1, 2, 3], vec![4, 5], vec![6]]
+        );
+    }
+
+    #[test]
+    fn orders_topologically() {
+        let edges = |node: u32| match node {
+            1 => vec![3, 2],
+            2 => vec![4],
+            3 => vec![4],
+            _ => vec![],
+        };
+
+        assert_eq!(
+            topological_sort(&edges, [4, 3, 2, 1]),
+            Some(vec![1, 3, 2, 4])
+        );
+
+        let cyclic = |node: u32| vec![(node + 1) % 3];
+        assert_eq!(topological_sort(&cyclic, 0..3), None);
+    }
+
+    #[test]
+    fn counts_paths_to_sinks() {
+        let edges = |node: u32| match node {
+            1 => vec![2, 3],
+            2 => vec![4, 5],
+            3 => vec![4],
+            4 => vec![5, 6],
+            _ => vec![],
+        };
+
+        assert_eq!(count_paths(&edges, 1, |node| node == 5), Some(3_u128));
+        assert_eq!(count_paths(&edges, 1, |node| node >= 5), Some(5_u128));
+        assert_eq!(count_paths(&edges, 6, |node| node == 5), Some(0_u128));
+
+        let lattice = |(x, y): (u32, u32)| match x + y < 60 {
+            true => vec![(x + 1, y), (x, y + 1)],
+            false => vec![],
+        };
+
+        assert_eq!(count_paths(&lattice, (0, 0), |_| false), Some(0_u128));
+        assert_eq!(
+            count_paths(&lattice, (0, 0), |(x, y)| x + y == 60),
+            Some(1_u128 << 60)
+        );
+        assert_eq!(
+            count_paths(&lattice, (0, 0), |(x, y)| x + y == 60)
+                .map(|paths: BigUint| paths.to_string()),
+            Some(String::from("1152921504606846976"))
+        );
+
+        let cyclic = |node: u32| vec![(node + 1) % 3, 3];
+        assert_eq!(count_paths::<_, u128>(&cyclic, 0, |node| node == 3), None);
+    }
+}
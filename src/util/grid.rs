@@ -0,0 +1,212 @@
+use super::{ParseError, Position2D};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_string(
+        input: &str,
+        parse_cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+
+            for (x, character) in line.chars().enumerate() {
+                let cell = parse_cell(character).ok_or_else(|| {
+                    ParseError::new(format!("unexpected character '{character}'"))
+                        .at_line(y + 1)
+                        .at_column(x + 1)
+                })?;
+
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            let expected = *width.get_or_insert(row_width);
+
+            if row_width != expected {
+                return Err(ParseError::new(format!(
+                    "expected {expected} cells but found {row_width}"
+                ))
+                .at_line(y + 1));
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, position: Position2D) -> Option<&T> {
+        self.index(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position2D) -> Option<&mut T> {
+        self.index(position).map(|index| &mut self.cells[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position2D, &T)> {
+        self.cells.iter().enumerate().map(|(index, cell)| {
+            (
+                Position2D::new(index % self.width, index / self.width),
+                cell,
+            )
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = match x < self.width {
+            true => self.height,
+            false => 0,
+        };
+
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn neighbors(&self, position: Position2D) -> impl Iterator<Item = Position2D> {
+        position.neighbors(self.width, self.height)
+    }
+
+    pub fn neighbors_with_diagonals(
+        &self,
+        position: Position2D,
+    ) -> impl Iterator<Item = Position2D> {
+        position.neighbors_with_diagonals(self.width, self.height)
+    }
+
+    fn index(&self, Position2D { x, y }: Position2D) -> Option<usize> {
+        match x < self.width && y < self.height {
+            true => Some(y * self.width + x),
+            false => None,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid_from_string(input: &str) -> Grid<char> {
+        Grid::from_string(input, |character| match character {
+            '.' | '#' => Some(character),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_character_map() {
+        let grid = grid_from_string("#..\n.#.\n");
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(Position2D::new(1, 1)), Some(&'#'));
+        assert_eq!(grid.get(Position2D::new(2, 0)), Some(&'.'));
+        assert_eq!(grid.get(Position2D::new(3, 0)), None);
+        assert_eq!(grid.get(Position2D::new(0, 2)), None);
+        assert_eq!(grid.to_string(), "#..\n.#.");
+    }
+
+    #[test]
+    fn reports_malformed_maps() {
+        let result = Grid::from_string("..\n.x", |character| (character == '.').then_some(()));
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2, column 2: unexpected character 'x'"))
+        );
+
+        let result = Grid::from_string("..\n...", |character| (character == '.').then_some(()));
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2: expected 2 cells but found 3"))
+        );
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let mut grid = grid_from_string("#..\n.#.\n..#");
+
+        if let Some(cell) = grid.get_mut(Position2D::new(0, 2)) {
+            *cell = '#';
+        }
+
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["#..", ".#.", "#.#"]);
+
+        let column: String = grid.column(0).collect();
+        assert_eq!(column, "#.#");
+        assert_eq!(grid.column(3).count(), 0);
+
+        let occupied: Vec<Position2D> = grid
+            .iter()
+            .filter(|(_, cell)| **cell == '#')
+            .map(|(position, _)| position)
+            .collect();
+        assert_eq!(
+            occupied,
+            [(0, 0), (1, 1), (0, 2), (2, 2)]
+                .map(Position2D::from)
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn parses_empty_input() {
+        let grid = grid_from_string("");
+
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+    }
+}
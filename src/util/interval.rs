@@ -0,0 +1,264 @@
+use std::cmp::{max, min};
+
+pub trait Discrete: Copy + Ord {
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_discrete {
+    ($($integer:ty),*) => {
+        $(
+            impl Discrete for $integer {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end.abs_diff(start) as u128).saturating_add(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Discrete> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T: Discrete> Interval<T> {
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn length(&self) -> u128 {
+        match self.is_empty() {
+            true => 0,
+            false => T::count(self.start, self.end),
+        }
+    }
+
+    fn precedes(&self, other: &Self) -> bool {
+        self.end
+            .successor()
+            .is_some_and(|successor| successor < other.start)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.precedes(&interval));
+
+        let mut merged = interval;
+        let mut last = first;
+
+        while let Some(existing) = self.intervals.get(last) {
+            if merged.precedes(existing) {
+                break;
+            }
+
+            merged.start = min(merged.start, existing.start);
+            merged.end = max(merged.end, existing.end);
+            last += 1;
+        }
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end < value);
+
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.start <= value)
+    }
+
+    pub fn length(&self) -> u128 {
+        self.intervals.iter().map(Interval::length).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        other.iter().for_each(|interval| result.insert(*interval));
+
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+        let (mut left, mut right) = (self.iter().peekable(), other.iter().peekable());
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            result.insert(Interval::new(max(a.start, b.start), min(a.end, b.end)));
+
+            match a.end < b.end {
+                true => left.next(),
+                false => right.next(),
+            };
+        }
+
+        result
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = Self::new();
+
+        for interval in self.iter() {
+            let mut start = Some(interval.start);
+            let first = other
+                .intervals
+                .partition_point(|removed| removed.end < interval.start);
+
+            for removed in other.intervals[first..].iter() {
+                let Some(current) = start else {
+                    break;
+                };
+
+                if removed.start > interval.end {
+                    break;
+                }
+
+                if let Some(end) = removed.start.predecessor() {
+                    result.insert(Interval::new(current, end));
+                }
+
+                start = removed.end.successor();
+            }
+
+            if let Some(current) = start {
+                result.insert(Interval::new(current, interval.end));
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Discrete> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        intervals
+            .into_iter()
+            .for_each(|interval| set.insert(interval));
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|(start, end)| Interval::new(*start, *end))
+            .collect()
+    }
+
+    fn spans(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter()
+            .map(|interval| (interval.start, interval.end))
+            .collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_intervals() {
+        let result = set(&[(16, 20), (3, 5), (12, 18), (10, 14)]);
+        assert_eq!(spans(&result), vec![(3, 5), (10, 20)]);
+
+        let result = set(&[(1, 2), (3, 4), (6, 6), (8, 9), (5, 7)]);
+        assert_eq!(spans(&result), vec![(1, 9)]);
+
+        let result = set(&[(5, 3), (i32::MAX - 1, i32::MAX), (i32::MIN, i32::MIN)]);
+        assert_eq!(
+            spans(&result),
+            vec![(i32::MIN, i32::MIN), (i32::MAX - 1, i32::MAX)]
+        );
+    }
+
+    #[test]
+    fn answers_membership_and_length() {
+        let result = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+
+        assert!(result.contains(3));
+        assert!(result.contains(17));
+        assert!(!result.contains(8));
+        assert!(!result.contains(21));
+        assert_eq!(result.length(), 14);
+
+        assert_eq!(Interval::new(3_u64, 5).length(), 3);
+        assert_eq!(Interval::new(5_u64, 3).length(), 0);
+        assert_eq!(Interval::new(0, u64::MAX).length(), 1 << 64);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(5, 22), (28, 40)]);
+
+        assert_eq!(spans(&a.union(&b)), vec![(1, 40)]);
+        assert_eq!(
+            spans(&a.intersection(&b)),
+            vec![(5, 10), (20, 22), (28, 30)]
+        );
+        assert_eq!(spans(&a.difference(&b)), vec![(1, 4), (23, 27)]);
+        assert_eq!(spans(&b.difference(&a)), vec![(11, 19), (31, 40)]);
+
+        let holes = set(&[(2, 2), (4, 5), (9, 12)]);
+        assert_eq!(
+            spans(&a.difference(&holes)),
+            vec![(1, 1), (3, 3), (6, 8), (20, 30)]
+        );
+        assert!(a.difference(&a).iter().next().is_none());
+    }
+}
@@ -1,5 +1,31 @@
-mod io;
+mod arithmetic;
+mod big_uint;
+mod disjoint_set;
+mod graph;
+mod grid;
+mod interval;
+mod panic;
+pub mod parse;
+mod parse_error;
+mod point_3;
 mod position_2d;
+#[cfg(test)]
+mod random;
+mod vector_2d;
 
-pub use io::read_input;
+pub use arithmetic::{Arithmetic, product, set_checked, sum};
+pub use big_uint::BigUint;
+pub use disjoint_set::DisjointSet;
+pub use graph::{
+    Graph, Unweighted, WeightedGraph, a_star, bfs, connected_components, count_paths, dijkstra,
+    topological_sort,
+};
+pub use grid::Grid;
+pub use interval::{Interval, IntervalSet};
+pub use panic::catch_panic;
+pub use parse_error::{ParseError, parse_lines};
+pub use point_3::Point3;
 pub use position_2d::Position2D;
+#[cfg(test)]
+pub use random::Random;
+pub use vector_2d::Vector2D;
@@ -0,0 +1,87 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    panic::{self, AssertUnwindSafe, catch_unwind},
+    sync::Once,
+};
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+// Callers report caught panics themselves, so the panic hook stays silent while `f` runs on this
+// thread and only records where the panic happened. Panics anywhere else still reach the previous
+// hook.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    QUIET_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| match QUIET.get() {
+            true => LOCATION.set(info.location().map(ToString::to_string)),
+            false => hook(info),
+        }));
+    });
+
+    let quiet = QUIET.replace(true);
+    let result = catch_unwind(AssertUnwindSafe(f));
+    QUIET.set(quiet);
+
+    result.map_err(|payload| {
+        let message = panic_message(payload.as_ref());
+
+        match LOCATION.take() {
+            Some(location) => format!("{message} at {location}"),
+            None => message,
+        }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    String::from("unknown panic")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn extracts_panic_messages() {
+        let payload = catch_unwind(|| panic!("static message")).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "static message");
+
+        let payload = catch_unwind(|| panic!("formatted {}", 42)).unwrap_err();
+        assert_eq!(panic_message(payload.as_ref()), "formatted 42");
+    }
+
+    #[test]
+    fn catches_panics_quietly() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let result = catch_panic(|| catch_panic(|| -> u8 { panic!("inner") }));
+        assert!(
+            result
+                .unwrap()
+                .unwrap_err()
+                .starts_with("inner at src/util/panic.rs:")
+        );
+
+        let result = catch_panic(|| -> u8 { panic!("reported once") });
+        assert!(
+            result
+                .unwrap_err()
+                .starts_with("reported once at src/util/panic.rs:")
+        );
+        assert!(!QUIET.get());
+    }
+}
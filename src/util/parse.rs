@@ -0,0 +1,238 @@
+use super::{Grid, ParseError, parse_lines};
+use std::{ops::Range, str::FromStr};
+
+pub fn integer<T: FromStr>(expected: &'static str) -> impl Fn(&str) -> Result<T, ParseError> {
+    move |input| {
+        input
+            .parse()
+            .map_err(|_| ParseError::new(format!("expected {expected}")).at_column(1))
+    }
+}
+
+pub fn lines<T>(
+    item: impl Fn(&str) -> Result<T, ParseError>,
+) -> impl Fn(&str) -> Result<Vec<T>, ParseError> {
+    move |input| parse_lines(input, &item)
+}
+
+pub fn separated<T>(
+    separator: &'static str,
+    item: impl Fn(&str) -> Result<T, ParseError>,
+) -> impl Fn(&str) -> Result<Vec<T>, ParseError> {
+    move |input| {
+        let mut column = 0;
+
+        input
+            .split(separator)
+            .map(|entry| {
+                let result = item(entry).map_err(|error| error.at_column(1).shift_column(column));
+                column += entry.len() + separator.len();
+                result
+            })
+            .collect()
+    }
+}
+
+pub fn fields<T, const N: usize>(
+    separator: &'static str,
+    expected: &'static str,
+    item: impl Fn(&str) -> Result<T, ParseError>,
+) -> impl Fn(&str) -> Result<[T; N], ParseError> {
+    move |input| {
+        separated(separator, &item)(input)?
+            .try_into()
+            .map_err(|_| ParseError::new(format!("expected {expected}")))
+    }
+}
+
+pub fn pair<A, B>(
+    separator: &'static str,
+    expected: &'static str,
+    first: impl Fn(&str) -> Result<A, ParseError>,
+    second: impl Fn(&str) -> Result<B, ParseError>,
+) -> impl Fn(&str) -> Result<(A, B), ParseError> {
+    move |input| {
+        let (left, right) = input
+            .split_once(separator)
+            .ok_or_else(|| ParseError::new(format!("expected {expected}")))?;
+
+        let offset = left.len() + separator.len();
+
+        Ok((
+            first(left)?,
+            second(right).map_err(|error| error.at_column(1).shift_column(offset))?,
+        ))
+    }
+}
+
+pub fn sections<A, B>(
+    expected: &'static str,
+    first: impl Fn(&str) -> Result<A, ParseError>,
+    second: impl Fn(&str) -> Result<B, ParseError>,
+) -> impl Fn(&str) -> Result<(A, B), ParseError> {
+    move |input| {
+        let (head, tail) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new(format!("expected {expected}")))?;
+
+        Ok((
+            first(head)?,
+            second(tail).map_err(|error| error.shift_line(head.lines().count() + 1))?,
+        ))
+    }
+}
+
+pub fn blocks<T>(
+    item: impl Fn(&str) -> Result<T, ParseError>,
+) -> impl Fn(&str) -> Result<Vec<T>, ParseError> {
+    move |input| {
+        let mut line = 0;
+
+        input
+            .split("\n\n")
+            .map(|block| {
+                let result = item(block).map_err(|error| error.shift_line(line));
+                line += block.lines().count() + 1;
+                result
+            })
+            .collect()
+    }
+}
+
+pub fn columns<T>(
+    ranges: Vec<Range<usize>>,
+    item: impl Fn(&str) -> Result<T, ParseError>,
+) -> impl Fn(&str) -> Result<Vec<T>, ParseError> {
+    move |line| {
+        ranges
+            .iter()
+            .map(|range| {
+                let cell: String = line.chars().skip(range.start).take(range.len()).collect();
+
+                item(&cell).map_err(|error| error.shift_column(range.start))
+            })
+            .collect()
+    }
+}
+
+pub fn grid<T>(cell: impl Fn(char) -> Option<T>) -> impl Fn(&str) -> Result<Grid<T>, ParseError> {
+    move |input| Grid::from_string(input, &cell)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(integer::<u32>("a number")("42"), Ok(42));
+        assert_eq!(
+            integer::<u32>("a number")("4x").unwrap_err().to_string(),
+            "column 1: expected a number"
+        );
+    }
+
+    #[test]
+    fn parses_separated_lists_with_columns() {
+        let numbers = lines(separated(",", integer::<u32>("a number")));
+
+        assert_eq!(numbers("1,2\n3"), Ok(vec![vec![1, 2], vec![3]]));
+        assert_eq!(
+            numbers("1,2\n3,,4").unwrap_err().to_string(),
+            "line 2, column 3: expected a number"
+        );
+
+        let point = fields::<u32, 3>(", ", "3 numbers", integer("a number"));
+
+        assert_eq!(point("1, 2, 3"), Ok([1, 2, 3]));
+        assert_eq!(point("1, 2").unwrap_err().to_string(), "expected 3 numbers");
+        assert_eq!(
+            point("1, 2, x").unwrap_err().to_string(),
+            "column 7: expected a number"
+        );
+    }
+
+    #[test]
+    fn parses_pairs() {
+        let range = pair(
+            "..",
+            "a range like 3..5",
+            integer::<u32>("a start"),
+            integer::<u32>("an end"),
+        );
+
+        assert_eq!(range("3..5"), Ok((3, 5)));
+        assert_eq!(
+            range("3-5").unwrap_err().to_string(),
+            "expected a range like 3..5"
+        );
+        assert_eq!(
+            range("13..x").unwrap_err().to_string(),
+            "column 5: expected an end"
+        );
+    }
+
+    #[test]
+    fn parses_sections_and_blocks() {
+        let numbers = || lines(integer::<u32>("a number"));
+        let database = sections("a blank line", numbers(), numbers());
+
+        assert_eq!(database("1\n2\n\n3"), Ok((vec![1, 2], vec![3])));
+        assert_eq!(
+            database("1\n2\n\n3\nx").unwrap_err().to_string(),
+            "line 5, column 1: expected a number"
+        );
+        assert_eq!(
+            database("1\n2").unwrap_err().to_string(),
+            "expected a blank line"
+        );
+
+        let groups = blocks(numbers());
+
+        assert_eq!(
+            groups("1\n\n2\n3\n\n4"),
+            Ok(vec![vec![1], vec![2, 3], vec![4]])
+        );
+        assert_eq!(
+            groups("1\n\n2\n3\n\ny").unwrap_err().to_string(),
+            "line 6, column 1: expected a number"
+        );
+    }
+
+    #[test]
+    fn parses_fixed_width_columns() {
+        let digits = |cell: &str| match cell.find(|character: char| !character.is_ascii_digit()) {
+            Some(index) => Err(ParseError::new("expected a digit").at_column(index + 1)),
+            None => Ok(cell.to_string()),
+        };
+        let table = lines(columns(vec![0..2, 3..6], digits));
+
+        assert_eq!(
+            table("12 345\n67 8"),
+            Ok(vec![
+                vec![String::from("12"), String::from("345")],
+                vec![String::from("67"), String::from("8")],
+            ])
+        );
+        assert_eq!(
+            table("12 345\n67 8x9").unwrap_err().to_string(),
+            "line 2, column 5: expected a digit"
+        );
+    }
+
+    #[test]
+    fn parses_character_grids() {
+        let map = grid(|character| match character {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        });
+
+        let walls = map("#.\n.#").unwrap();
+        assert_eq!(walls.width(), 2);
+        assert_eq!(
+            map("#.\n.?").err().map(|error| error.to_string()),
+            Some(String::from("line 2, column 2: unexpected character '?'"))
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    pub fn shift_line(mut self, offset: usize) -> Self {
+        self.line = self.line.map(|line| line + offset);
+        self
+    }
+
+    pub fn shift_column(mut self, offset: usize) -> Self {
+        self.column = self.column.map(|column| column + offset);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "column {column}: ")?,
+            (None, None) => {}
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse_lines<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|error| error.at_line(index + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_number(input: &str) -> Result<u32, ParseError> {
+        input
+            .parse()
+            .map_err(|_| ParseError::new("expected a number").at_column(1))
+    }
+
+    #[test]
+    fn displays_position() {
+        let error = ParseError::new("expected 3 comma-separated integers");
+        assert_eq!(error.to_string(), "expected 3 comma-separated integers");
+
+        let error = error.at_line(14);
+        assert_eq!(
+            error.to_string(),
+            "line 14: expected 3 comma-separated integers"
+        );
+
+        let error = error.at_column(5).at_line(2);
+        assert_eq!(
+            error.to_string(),
+            "line 14, column 5: expected 3 comma-separated integers"
+        );
+    }
+
+    #[test]
+    fn parses_lines_with_line_numbers() {
+        assert_eq!(parse_lines("1\n2\n3", parse_number), Ok(vec![1, 2, 3]));
+
+        let error = parse_lines("1\n2\nthree", parse_number).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected a number");
+
+        let error = error.shift_line(4).shift_column(2);
+        assert_eq!(error.to_string(), "line 7, column 3: expected a number");
+    }
+}
@@ -0,0 +1,140 @@
+use super::{
+    ParseError,
+    parse::{fields, integer},
+};
+use std::{
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = fields(",", "3 comma-separated integers", |entry| {
+            integer("an integer")(entry.trim())
+        })(input)?;
+
+        Ok(Self::new(x, y, z))
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl Point3 {
+    pub fn squared_distance(&self, other: &Self) -> u128 {
+        [
+            self.x.abs_diff(other.x),
+            self.y.abs_diff(other.y),
+            self.z.abs_diff(other.z),
+        ]
+        .into_iter()
+        .map(|difference| u128::from(difference).pow(2))
+        .sum()
+    }
+
+    pub fn euclidean_distance(&self, other: &Self) -> f64 {
+        (self.squared_distance(other) as f64).sqrt()
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::parse_lines;
+
+    #[test]
+    fn parses_points() {
+        assert_eq!("162,817,812".parse(), Ok(Point3::new(162, 817, 812)));
+        assert_eq!("-1, 2, -3".parse(), Ok(Point3::new(-1, 2, -3)));
+
+        let result = parse_lines("1,2,3\n4,5", str::parse::<Point3>);
+        assert_eq!(
+            result.err().map(|error| error.to_string()),
+            Some(String::from("line 2: expected 3 comma-separated integers"))
+        );
+
+        assert!("1,2,3,4".parse::<Point3>().is_err());
+        assert_eq!(Point3::new(1, -2, 3).to_string(), "1,-2,3");
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Point3::new(162, 817, 812);
+        let b = Point3::new(425, 690, 689);
+
+        assert_eq!(a.squared_distance(&b), 100427);
+        assert_eq!(a.manhattan_distance(&b), 513);
+        assert!((a.euclidean_distance(&b) - 316.9022).abs() < 1e-4);
+
+        let far = Point3::new(i64::MIN, i64::MIN, i64::MIN);
+        let near = Point3::new(i64::MAX, i64::MIN, i64::MIN);
+        assert_eq!(far.squared_distance(&far), 0);
+        assert!(far.squared_distance(&near) > u128::from(u64::MAX));
+    }
+
+    #[test]
+    fn applies_arithmetic() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(4, -5, 6);
+
+        assert_eq!(a + b, Point3::new(5, -3, 9));
+        assert_eq!(b - a, Point3::new(3, -7, 3));
+        assert_eq!(-a, Point3::new(-1, -2, -3));
+        assert_eq!(a * 3, Point3::new(3, 6, 9));
+    }
+}
@@ -1 +1,133 @@
-pub type Position2D = (usize, usize);
+use super::Vector2D;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct Position2D {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position2D {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<(usize, usize)> for Position2D {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl Position2D {
+    pub fn checked_add(self, vector: Vector2D) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(vector.x)?,
+            self.y.checked_add_signed(vector.y)?,
+        ))
+    }
+
+    pub fn wrapping_add(self, vector: Vector2D, width: usize, height: usize) -> Self {
+        Self::new(
+            wrap(self.x, vector.x, width),
+            wrap(self.y, vector.y, height),
+        )
+    }
+
+    pub fn offset_to(self, other: Self) -> Vector2D {
+        Vector2D::new(
+            other.x as isize - self.x as isize,
+            other.y as isize - self.y as isize,
+        )
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn neighbors(self, width: usize, height: usize) -> impl Iterator<Item = Self> {
+        self.neighbors_by(&Vector2D::ORTHOGONAL, width, height)
+    }
+
+    pub fn neighbors_with_diagonals(
+        self,
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = Self> {
+        self.neighbors_by(&Vector2D::ALL, width, height)
+    }
+
+    fn neighbors_by(
+        self,
+        directions: &'static [Vector2D],
+        width: usize,
+        height: usize,
+    ) -> impl Iterator<Item = Self> {
+        directions
+            .iter()
+            .filter_map(move |direction| self.checked_add(*direction))
+            .filter(move |position| position.x < width && position.y < height)
+    }
+}
+
+fn wrap(value: usize, offset: isize, size: usize) -> usize {
+    let size = size as isize;
+
+    (value as isize + offset).rem_euclid(size) as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn adds_vectors() {
+        let position = Position2D::new(0, 3);
+
+        assert_eq!(
+            position.checked_add(Vector2D::DOWN),
+            Some(Position2D::new(0, 4))
+        );
+        assert_eq!(position.checked_add(Vector2D::LEFT), None);
+        assert_eq!(
+            position.wrapping_add(Vector2D::LEFT, 5, 4),
+            Position2D::new(4, 3)
+        );
+        assert_eq!(
+            position.wrapping_add(Vector2D::new(7, 1), 5, 4),
+            Position2D::new(2, 0)
+        );
+        assert_eq!(
+            position.offset_to(Position2D::new(2, 1)),
+            Vector2D::new(2, -2)
+        );
+    }
+
+    #[test]
+    fn measures_distances() {
+        let start = Position2D::new(1, 8);
+        let end = Position2D::new(4, 2);
+
+        assert_eq!(start.manhattan_distance(end), 9);
+        assert_eq!(start.chebyshev_distance(end), 6);
+    }
+
+    #[test]
+    fn finds_neighbors_within_bounds() {
+        let corner = Position2D::new(0, 0);
+
+        assert_eq!(
+            corner.neighbors(3, 3).collect::<Vec<_>>(),
+            vec![Position2D::new(1, 0), Position2D::new(0, 1)]
+        );
+        assert_eq!(corner.neighbors_with_diagonals(3, 3).count(), 3);
+        assert_eq!(
+            Position2D::new(1, 1).neighbors_with_diagonals(3, 3).count(),
+            8
+        );
+        assert_eq!(Position2D::new(2, 2).neighbors(3, 3).count(), 2);
+    }
+}
@@ -0,0 +1,29 @@
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Vector2D {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vector2D {
+    pub const UP: Self = Self::new(0, -1);
+    pub const DOWN: Self = Self::new(0, 1);
+    pub const LEFT: Self = Self::new(-1, 0);
+    pub const RIGHT: Self = Self::new(1, 0);
+
+    pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+
+    pub const ALL: [Self; 8] = [
+        Self::new(-1, -1),
+        Self::UP,
+        Self::new(1, -1),
+        Self::RIGHT,
+        Self::new(1, 1),
+        Self::DOWN,
+        Self::new(-1, 1),
+        Self::LEFT,
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::solution::Part;
+use std::{collections::BTreeMap, fmt};
+
+#[derive(Debug, PartialEq)]
+pub struct AnswerSheetError {
+    line: usize,
+    message: String,
+}
+
+impl AnswerSheetError {
+    fn new(line: usize, message: &str) -> Self {
+        Self {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AnswerSheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "answers line {}: {}", self.line, self.message)
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct AnswerSheet {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl AnswerSheet {
+    pub fn from_string(input: &str) -> Result<Self, AnswerSheetError> {
+        let mut answers = BTreeMap::new();
+        let mut day = None;
+
+        for (index, line) in input.lines().enumerate() {
+            let number = index + 1;
+            let line = strip_comment(line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[') {
+                let section = section
+                    .strip_suffix(']')
+                    .ok_or_else(|| AnswerSheetError::new(number, "unterminated section header"))?;
+
+                day = Some(parse_day(section).ok_or_else(|| {
+                    AnswerSheetError::new(number, "expected a section like [day_01]")
+                })?);
+
+                continue;
+            }
+
+            let day = day.ok_or_else(|| {
+                AnswerSheetError::new(number, "answers must follow a [day_NN] section")
+            })?;
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| AnswerSheetError::new(number, "expected 'part_N = answer'"))?;
+
+            let part = match key.trim() {
+                "part_1" => 1,
+                "part_2" => 2,
+                _ => return Err(AnswerSheetError::new(number, "expected part_1 or part_2")),
+            };
+
+            let value = parse_value(value.trim())
+                .ok_or_else(|| AnswerSheetError::new(number, "expected a number or string"))?;
+
+            answers.insert((day, part), value);
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+impl AnswerSheet {
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(day, part.number()))
+            .map(|answer| answer.as_str())
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+
+    for (index, character) in line.char_indices() {
+        match character {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn parse_day(section: &str) -> Option<u8> {
+    section.trim().strip_prefix("day_")?.parse().ok()
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        return quoted.strip_suffix('"').map(String::from);
+    }
+
+    let digits = value.replace('_', "");
+
+    match !digits.is_empty() && digits.chars().all(|character| character.is_ascii_digit()) {
+        true => Some(digits),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_answer_sheet() {
+        let input = "# answers for the embedded inputs\n[day_01]\npart_1 = 1120\npart_2 = \"6554\" # quoted\n\n[day_8]\npart_2 = 51_294_528";
+
+        let sheet = AnswerSheet::from_string(input).unwrap();
+
+        assert_eq!(sheet.expected(1, Part::One), Some("1120"));
+        assert_eq!(sheet.expected(1, Part::Two), Some("6554"));
+        assert_eq!(sheet.expected(8, Part::One), None);
+        assert_eq!(sheet.expected(8, Part::Two), Some("51294528"));
+    }
+
+    #[test]
+    fn reports_malformed_lines() {
+        assert_eq!(
+            AnswerSheet::from_string("part_1 = 3"),
+            Err(AnswerSheetError::new(
+                1,
+                "answers must follow a [day_NN] section"
+            ))
+        );
+        assert_eq!(
+            AnswerSheet::from_string("[day_01]\npart_3 = 3"),
+            Err(AnswerSheetError::new(2, "expected part_1 or part_2"))
+        );
+        assert_eq!(
+            AnswerSheet::from_string("[day_01]\n\npart_1 = three"),
+            Err(AnswerSheetError::new(3, "expected a number or string"))
+        );
+        assert_eq!(
+            AnswerSheet::from_string("[day_one]")
+                .unwrap_err()
+                .to_string(),
+            "answers line 1: expected a section like [day_01]"
+        );
+    }
+}
@@ -0,0 +1,150 @@
+mod answer_sheet;
+
+pub use answer_sheet::AnswerSheet;
+
+use crate::solution::{DayReport, Example, Part, Registration};
+
+pub const ANSWERS: &str = include_str!("../../answers.toml");
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String },
+    Missing,
+    Panicked,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Verification {
+    pub day: u8,
+    pub part: Part,
+    pub actual: String,
+    pub outcome: Outcome,
+}
+
+pub fn verify(day: u8, report: &DayReport, sheet: &AnswerSheet) -> Vec<Verification> {
+    report
+        .parts
+        .iter()
+        .map(|part| {
+            let (actual, outcome) = match &part.answer {
+                Err(message) => (message.clone(), Outcome::Panicked),
+                Ok(answer) => {
+                    let actual = answer.to_string();
+
+                    let outcome = match sheet.expected(day, part.part) {
+                        None => Outcome::Missing,
+                        Some(expected) if expected == actual => Outcome::Pass,
+                        Some(expected) => Outcome::Fail {
+                            expected: expected.to_string(),
+                        },
+                    };
+
+                    (actual, outcome)
+                }
+            };
+
+            Verification {
+                day,
+                part: part.part,
+                actual,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+pub fn verify_example(
+    registration: &Registration,
+    example: &Example,
+) -> Result<Vec<Verification>, String> {
+    let sheet = AnswerSheet::from_string(example.answers)
+        .map_err(|error| format!("example {}, {error}", example.name))?;
+
+    let parts: Vec<Part> = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|part| sheet.expected(registration.day, *part).is_some())
+        .collect();
+
+    let report = registration
+        .solution
+        .run(example.input, &parts)
+        .map_err(|error| format!("example {}, {error}", example.name))?;
+
+    Ok(verify(registration.day, &report, &sheet))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::{Answer, PartReport, REGISTRY};
+    use std::time::Duration;
+
+    #[test]
+    fn compares_answers_with_sheet() {
+        let sheet = AnswerSheet::from_string("[day_05]\npart_1 = 638\npart_2 = 1").unwrap();
+
+        let report = DayReport {
+            parse: Duration::ZERO,
+            parts: vec![
+                PartReport {
+                    part: Part::One,
+                    answer: Ok(Answer::from(638_u32)),
+                    duration: Duration::ZERO,
+                },
+                PartReport {
+                    part: Part::Two,
+                    answer: Ok(Answer::from(2_u32)),
+                    duration: Duration::ZERO,
+                },
+            ],
+        };
+
+        let outcomes: Vec<Outcome> = verify(5, &report, &sheet)
+            .into_iter()
+            .map(|verification| verification.outcome)
+            .collect();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                Outcome::Pass,
+                Outcome::Fail {
+                    expected: String::from("1")
+                }
+            ]
+        );
+
+        let outcomes: Vec<Outcome> = verify(6, &report, &sheet)
+            .into_iter()
+            .map(|verification| verification.outcome)
+            .collect();
+
+        assert_eq!(outcomes, vec![Outcome::Missing, Outcome::Missing]);
+    }
+
+    #[test]
+    fn solves_registered_examples() {
+        for registration in REGISTRY {
+            assert!(
+                !registration.examples.is_empty(),
+                "day {} has no examples",
+                registration.day
+            );
+
+            for example in registration.examples {
+                for verification in verify_example(registration, example).unwrap() {
+                    assert_eq!(
+                        verification.outcome,
+                        Outcome::Pass,
+                        "day {} part {} on {} got {}",
+                        registration.day,
+                        verification.part.number(),
+                        example.name,
+                        verification.actual
+                    );
+                }
+            }
+        }
+    }
+}